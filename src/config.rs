@@ -1,6 +1,12 @@
+use glob::Pattern;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::Path;
+use std::path::{Component, Path, PathBuf};
+
+/// Folders of installed packages, which tsc does not match with wildcards
+/// unless the pattern names them
+const PACKAGE_FOLDERS: [&str; 3] = ["node_modules", "bower_components", "jspm_packages"];
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -25,6 +31,12 @@ pub struct Config {
 
     #[serde(default)]
     pub conditions: Vec<String>,
+
+    #[serde(default)]
+    pub nominal_classes: Vec<String>,
+
+    #[serde(default)]
+    pub type_files: Vec<String>,
 }
 
 fn default_validator_pattern() -> String {
@@ -57,6 +69,8 @@ impl Default for Config {
             follow_external_imports: default_follow_external_imports(),
             exclude_packages: Vec::new(),
             conditions: Vec::new(),
+            nominal_classes: Vec::new(),
+            type_files: Vec::new(),
         }
     }
 }
@@ -76,4 +90,234 @@ impl Config {
         self.validator_pattern
             .replace("%(type)", r"([A-Z][a-zA-Z]+)")
     }
+
+    /// Glob patterns for the ambient declaration files to load, which are
+    /// `typeFiles` or otherwise the `.d.ts` files matched by the `include` and
+    /// `compilerOptions.typeRoots` of `tsconfig.json`
+    pub fn get_type_file_patterns(&self) -> Vec<TypeFilePattern> {
+        if !self.type_files.is_empty() {
+            return self
+                .type_files
+                .iter()
+                .map(|pattern| TypeFilePattern::new(pattern.clone()))
+                .collect();
+        }
+
+        let Ok(content) = fs::read_to_string("tsconfig.json") else {
+            return Vec::new();
+        };
+        match serde_json::from_str(&strip_json_comments(&content)) {
+            Ok(tsconfig) => tsconfig_type_file_patterns(&tsconfig),
+            Err(e) => {
+                eprintln!("Warning: Could not parse tsconfig.json: {}", e);
+                Vec::new()
+            }
+        }
+    }
+}
+
+/// A glob pattern for declaration files along with the patterns of the files
+/// and directories it skips
+pub struct TypeFilePattern {
+    pub pattern: String,
+    pub exclude: Vec<String>,
+}
+
+impl TypeFilePattern {
+    fn new(pattern: String) -> Self {
+        Self {
+            pattern,
+            exclude: Vec::new(),
+        }
+    }
+
+    /// Whether a file matched by the pattern is skipped, because it or a
+    /// directory containing it is excluded, or it is in a package folder such
+    /// as `node_modules` that the pattern does not name
+    pub fn excludes(&self, path: &Path) -> bool {
+        let path: PathBuf = path
+            .components()
+            .filter(|component| !matches!(component, Component::CurDir))
+            .collect();
+        let in_package_folder = path.components().any(|component| {
+            PACKAGE_FOLDERS
+                .iter()
+                .any(|folder| component.as_os_str() == *folder && !self.pattern.contains(folder))
+        });
+
+        in_package_folder
+            || self
+                .exclude
+                .iter()
+                .filter_map(|exclude| {
+                    let exclude = exclude.strip_prefix("./").unwrap_or(exclude);
+                    Pattern::new(exclude.trim_end_matches('/')).ok()
+                })
+                .any(|exclude| {
+                    path.ancestors()
+                        .any(|ancestor| exclude.matches_path(ancestor))
+                })
+    }
+}
+
+/// The declaration files of a tsconfig's `include`, which skip its `exclude`
+/// or otherwise its `outDir` as tsc does, and of its `typeRoots`
+fn tsconfig_type_file_patterns(tsconfig: &serde_json::Value) -> Vec<TypeFilePattern> {
+    let exclude: Vec<String> = match tsconfig["exclude"].as_array() {
+        Some(exclude) => exclude
+            .iter()
+            .filter_map(|pattern| pattern.as_str())
+            .map(str::to_string)
+            .collect(),
+        None => tsconfig["compilerOptions"]["outDir"]
+            .as_str()
+            .map(str::to_string)
+            .into_iter()
+            .collect(),
+    };
+
+    let mut patterns = Vec::new();
+    if let Some(include) = tsconfig["include"].as_array() {
+        patterns.extend(
+            include
+                .iter()
+                .filter_map(|pattern| pattern.as_str())
+                .map(|pattern| TypeFilePattern {
+                    pattern: declaration_file_pattern(pattern),
+                    exclude: exclude.clone(),
+                }),
+        );
+    }
+    if let Some(type_roots) = tsconfig["compilerOptions"]["typeRoots"].as_array() {
+        patterns.extend(
+            type_roots
+                .iter()
+                .filter_map(|root| root.as_str())
+                .map(|root| {
+                    TypeFilePattern::new(format!("{}/**/*.d.ts", root.trim_end_matches('/')))
+                }),
+        );
+    }
+    patterns
+}
+
+/// Narrows a tsconfig `include` pattern such as `src/**/*` or `types` to the
+/// declaration files it matches
+fn declaration_file_pattern(include: &str) -> String {
+    let include = include.trim_end_matches('/');
+    if include.ends_with(".d.ts") {
+        include.to_string()
+    } else if let Some(prefix) = include
+        .strip_suffix(".ts")
+        .or_else(|| include.strip_suffix(".tsx"))
+    {
+        format!("{}.d.ts", prefix)
+    } else if include.ends_with('*') {
+        format!("{}.d.ts", include)
+    } else {
+        format!("{}/**/*.d.ts", include)
+    }
+}
+
+/// Removes the comments and trailing commas tsconfig.json allows
+fn strip_json_comments(content: &str) -> String {
+    let mut result = String::new();
+    let mut chars = content.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            result.push(c);
+            if c == '\\' {
+                result.extend(chars.next());
+            } else if c == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+
+        match (c, chars.peek()) {
+            ('"', _) => {
+                in_string = true;
+                result.push(c);
+            }
+            ('/', Some('/')) => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        result.push(c);
+                        break;
+                    }
+                }
+            }
+            ('/', Some('*')) => {
+                chars.next();
+                let mut previous = ' ';
+                for c in chars.by_ref() {
+                    if previous == '*' && c == '/' {
+                        break;
+                    }
+                    previous = c;
+                }
+            }
+            _ => result.push(c),
+        }
+    }
+
+    Regex::new(r",(\s*[}\]])")
+        .unwrap()
+        .replace_all(&result, "$1")
+        .into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn skips_package_folders_and_out_dir_without_exclude() {
+        let patterns = tsconfig_type_file_patterns(&json!({
+            "compilerOptions": { "outDir": "dist", "typeRoots": ["./node_modules/@types"] },
+            "include": ["**/*"],
+        }));
+
+        assert_eq!(patterns[0].pattern, "**/*.d.ts");
+        assert!(patterns[0].excludes(Path::new("node_modules/big/index.d.ts")));
+        assert!(patterns[0].excludes(Path::new("packages/a/node_modules/b/index.d.ts")));
+        assert!(patterns[0].excludes(Path::new("dist/index.d.ts")));
+        assert!(!patterns[0].excludes(Path::new("./src/env.d.ts")));
+        // Type roots name the package folder they are in
+        assert_eq!(patterns[1].pattern, "./node_modules/@types/**/*.d.ts");
+        assert!(!patterns[1].excludes(Path::new("node_modules/@types/node/index.d.ts")));
+    }
+
+    #[test]
+    fn applies_exclude_to_include_patterns() {
+        let patterns = tsconfig_type_file_patterns(&json!({
+            "compilerOptions": { "outDir": "dist" },
+            "include": ["src", "types/*"],
+            "exclude": ["./src/legacy/", "**/*.test.d.ts"],
+        }));
+
+        assert_eq!(patterns[0].pattern, "src/**/*.d.ts");
+        assert_eq!(patterns[1].pattern, "types/*.d.ts");
+        assert!(patterns[0].excludes(Path::new("src/legacy/old.d.ts")));
+        assert!(patterns[0].excludes(Path::new("src/api.test.d.ts")));
+        assert!(!patterns[0].excludes(Path::new("src/api.d.ts")));
+        assert!(patterns[0].excludes(Path::new("src/node_modules/pkg/index.d.ts")));
+        // An explicit exclude replaces the default exclusion of `outDir`
+        assert!(!patterns[0].excludes(Path::new("dist/index.d.ts")));
+    }
+
+    #[test]
+    fn keeps_type_files_from_the_config() {
+        let config = Config {
+            type_files: vec!["node_modules/sdk/*.d.ts".to_string()],
+            ..Config::default()
+        };
+        let patterns = config.get_type_file_patterns();
+
+        assert_eq!(patterns[0].pattern, "node_modules/sdk/*.d.ts");
+        assert!(!patterns[0].excludes(Path::new("node_modules/sdk/index.d.ts")));
+    }
 }
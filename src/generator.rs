@@ -1,29 +1,53 @@
-use crate::parser::{EnumInfo, EnumValue, InterfaceInfo, PropertyInfo, ValidatorFunction};
+use crate::parser::{
+    format_number, is_identifier, quote_string, unquote_string, validator_type_name, ConstantInfo,
+    EnumInfo, EnumValue, InterfaceInfo, PropertyInfo, TypeAliasInfo, TypeParameterInfo,
+    ValidatorFunction, UNSUPPORTED_TYPE,
+};
+use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::Path;
 
 pub struct ValidatorGenerator {
     interfaces: HashMap<String, InterfaceInfo>,
+    type_aliases: HashMap<String, TypeAliasInfo>,
+    constants: HashMap<String, ConstantInfo>,
     enums: HashMap<String, EnumInfo>,
+    export_names: HashMap<String, String>,
+    nominal_classes: HashSet<String>,
     use_js_extensions: bool,
+    /// The function validating each type, by the name the type is recorded under
+    validator_names: HashMap<String, String>,
+    /// Declarations named by the types of inline predicates, such as the enum
+    /// in `(v: unknown): v is Status => ...`, which have to be imported
+    predicate_references: RefCell<HashSet<String>>,
 }
 
 impl ValidatorGenerator {
     pub fn new(
         interfaces: HashMap<String, InterfaceInfo>,
+        type_aliases: HashMap<String, TypeAliasInfo>,
+        constants: HashMap<String, ConstantInfo>,
         enums: HashMap<String, EnumInfo>,
+        export_names: HashMap<String, String>,
+        nominal_classes: Vec<String>,
         use_js_extensions: bool,
     ) -> Self {
         Self {
             interfaces,
+            type_aliases,
+            constants,
             enums,
+            export_names,
+            nominal_classes: nominal_classes.into_iter().collect(),
             use_js_extensions,
+            validator_names: HashMap::new(),
+            predicate_references: RefCell::new(HashSet::new()),
         }
     }
 
     pub fn generate_validators(
-        &self,
+        &mut self,
         validator_functions: &[ValidatorFunction],
         output_file_path: &str,
     ) -> String {
@@ -35,18 +59,94 @@ impl ValidatorGenerator {
         output.push_str("// To regenerate, run: bagsakan\n\n");
 
         // Deduplicate validator functions by name
+        let mut requested: HashMap<&str, &str> = HashMap::new();
+        for vf in validator_functions {
+            let interface_name = requested.entry(&vf.name).or_insert(&vf.interface_name);
+            if *interface_name != vf.interface_name {
+                eprintln!(
+                    "Warning: {} is called for {} from more than one module; validating the one declared in {}",
+                    vf.name,
+                    self.declared_name(&vf.interface_name),
+                    self.type_file_path(interface_name)
+                        .unwrap_or(interface_name)
+                );
+            }
+        }
         let mut seen = HashSet::new();
-        let mut validators: Vec<_> = validator_functions
+        let mut validators: Vec<ValidatorFunction> = validator_functions
             .iter()
-            .filter(|vf| seen.insert(&vf.name))
-            .filter_map(|vf| {
-                self.interfaces
-                    .get(&vf.interface_name)
-                    .map(|interface| (vf, interface))
+            .filter(|vf| seen.insert(vf.name.clone()))
+            .filter(|vf| self.is_validator_target(&vf.interface_name))
+            .map(|vf| ValidatorFunction {
+                name: vf.name.clone(),
+                interface_name: vf.interface_name.clone(),
             })
             .collect();
 
-        validators.sort_by(|a, b| a.0.name.cmp(&b.0.name));
+        // Dependencies are generated once per type, so types sharing a name
+        // across modules each get their own validator
+        self.validator_names.clear();
+        for validator in &validators {
+            self.validator_names
+                .entry(validator.interface_name.clone())
+                .or_insert_with(|| validator.name.clone());
+        }
+
+        // Property checks call `validate<Type>` for named types, so make sure a
+        // validator exists for every type reachable from the requested ones
+        let mut i = 0;
+        while i < validators.len() {
+            let mut referenced_types = HashSet::new();
+            self.collect_target_types(&validators[i].interface_name, &mut referenced_types);
+
+            let mut dependencies: Vec<_> = referenced_types.into_iter().collect();
+            dependencies.sort();
+            for type_name in dependencies {
+                if self.validator_names.contains_key(&type_name) {
+                    continue;
+                }
+
+                // A requested validator may already use the name derived from
+                // the type, as `validateContact` for a `Contact2` from another module
+                let base_name = validator_name(&type_name);
+                let mut name = base_name.clone();
+                let mut suffix = 2;
+                while !seen.insert(name.clone()) {
+                    name = format!("{}{}", base_name, suffix);
+                    suffix += 1;
+                }
+                self.validator_names.insert(type_name.clone(), name.clone());
+                validators.push(ValidatorFunction {
+                    name,
+                    interface_name: type_name,
+                });
+            }
+            i += 1;
+        }
+
+        validators.sort_by(|a, b| a.name.cmp(&b.name));
+
+        // Generate validators, which are needed before the imports to know the
+        // declarations named by inline predicates
+        self.predicate_references.borrow_mut().clear();
+        let bodies: Vec<String> = validators
+            .iter()
+            .filter_map(|validator| {
+                if let Some(interface) = self.interfaces.get(&validator.interface_name) {
+                    Some(self.generate_validator(&validator.name, interface))
+                } else if let Some(alias) = self.type_aliases.get(&validator.interface_name) {
+                    Some(self.generate_type_alias_validator(&validator.name, alias))
+                } else {
+                    self.enums.get(&validator.interface_name).map(|enum_info| {
+                        self.generate_enum_validator(
+                            &validator.name,
+                            &validator.interface_name,
+                            enum_info,
+                        )
+                    })
+                }
+            })
+            .collect();
 
         // Generate imports
         let imports = self.generate_imports(&validators, output_file_path);
@@ -55,50 +155,185 @@ impl ValidatorGenerator {
             output.push_str("\n\n");
         }
 
-        // Generate validators
-        for (i, (validator, interface)) in validators.iter().enumerate() {
-            if i > 0 {
-                output.push_str("\n\n");
+        output.push_str(&bodies.join("\n\n"));
+
+        output
+    }
+
+    /// The function generated for a named type, which differs from the one
+    /// derived from its name when that name is taken by a requested validator
+    fn type_validator_name(&self, type_name: &str) -> String {
+        self.validator_names
+            .get(type_name)
+            .cloned()
+            .unwrap_or_else(|| validator_name(type_name))
+    }
+
+    fn is_named_type(&self, type_name: &str) -> bool {
+        self.interfaces.contains_key(type_name) || self.type_aliases.contains_key(type_name)
+    }
+
+    /// Whether a validator can be generated for the type. Enums are validated
+    /// inline in property checks, so they only get a validator when requested
+    fn is_validator_target(&self, type_name: &str) -> bool {
+        self.is_named_type(type_name) || self.enums.contains_key(type_name)
+    }
+
+    fn named_type_parameters(&self, type_name: &str) -> &[TypeParameterInfo] {
+        if let Some(interface) = self.interfaces.get(type_name) {
+            &interface.type_parameters
+        } else if let Some(alias) = self.type_aliases.get(type_name) {
+            &alias.type_parameters
+        } else {
+            &[]
+        }
+    }
+
+    /// The name a type is declared with, which differs from the name it is
+    /// recorded under when types in different modules share a name
+    fn declared_name<'a>(&'a self, type_name: &'a str) -> &'a str {
+        if let Some(interface) = self.interfaces.get(type_name) {
+            &interface.declared_name
+        } else if let Some(alias) = self.type_aliases.get(type_name) {
+            &alias.declared_name
+        } else if let Some(enum_info) = self.enums.get(type_name) {
+            &enum_info.declared_name
+        } else if let Some(constant) = self.constants.get(type_name) {
+            &constant.declared_name
+        } else {
+            type_name
+        }
+    }
+
+    fn type_file_path(&self, type_name: &str) -> Option<&str> {
+        if let Some(interface) = self.interfaces.get(type_name) {
+            Some(&interface.file_path)
+        } else if let Some(alias) = self.type_aliases.get(type_name) {
+            Some(&alias.file_path)
+        } else if let Some(enum_info) = self.enums.get(type_name) {
+            Some(&enum_info.file_path)
+        } else {
+            self.constants
+                .get(type_name)
+                .map(|constant| constant.file_path.as_str())
+        }
+    }
+
+    fn collect_target_types(&self, type_name: &str, referenced_types: &mut HashSet<String>) {
+        for param in self.named_type_parameters(type_name) {
+            if let Some(constraint) = &param.constraint {
+                self.collect_referenced_types(constraint, referenced_types);
+            }
+            if let Some(default) = &param.default {
+                self.collect_referenced_types(default, referenced_types);
+            }
+        }
+
+        if let Some(interface) = self.interfaces.get(type_name) {
+            for prop in self.resolve_properties(interface) {
+                self.collect_referenced_types(&prop.type_annotation, referenced_types);
             }
-            output.push_str(&self.generate_validator(&validator.name, interface));
+        } else if let Some(alias) = self.type_aliases.get(type_name) {
+            self.collect_referenced_types(&alias.type_annotation, referenced_types);
         }
+    }
 
-        output
+    /// Returns the properties of an interface including those inherited from the
+    /// interfaces it extends, where properties redeclared in a derived interface
+    /// take precedence over the inherited ones
+    fn resolve_properties(&self, interface: &InterfaceInfo) -> Vec<PropertyInfo> {
+        let mut visiting = HashSet::new();
+        let mut properties = Vec::new();
+        self.collect_inherited_properties(interface, &mut visiting, &mut properties);
+        properties
     }
 
-    fn generate_imports(
+    fn collect_inherited_properties(
         &self,
-        validators: &[(&ValidatorFunction, &InterfaceInfo)],
-        output_file_path: &str,
-    ) -> String {
-        use std::collections::{HashMap, HashSet};
-        use std::path::Path;
+        interface: &InterfaceInfo,
+        visiting: &mut HashSet<String>,
+        properties: &mut Vec<PropertyInfo>,
+    ) {
+        // Guard against circular inheritance
+        if !visiting.insert(interface.name.clone()) {
+            return;
+        }
+
+        for base_type in &interface.extends {
+            let (base_name, type_arguments) =
+                split_type_reference(base_type).unwrap_or((base_type.as_str(), Vec::new()));
+            if let Some(base) = self.interfaces.get(base_name) {
+                let mut base_properties = Vec::new();
+                self.collect_inherited_properties(base, visiting, &mut base_properties);
+
+                // Specialize the base interface for the type arguments in the extends clause
+                let substitutions =
+                    type_parameter_substitutions(&base.type_parameters, &type_arguments);
+                for mut prop in base_properties {
+                    prop.type_annotation =
+                        substitute_type_parameters(&prop.type_annotation, &substitutions);
+                    merge_property(properties, prop);
+                }
+            } else if let Some(base_properties) = self.resolve_type_properties(base_type) {
+                // Interfaces can also extend object type aliases such as intersections
+                for prop in base_properties {
+                    merge_property(properties, prop);
+                }
+            }
+        }
+
+        for prop in &interface.properties {
+            merge_property(properties, prop.clone());
+        }
+
+        visiting.remove(&interface.name);
+    }
 
+    fn generate_imports(&self, validators: &[ValidatorFunction], output_file_path: &str) -> String {
         let output_path = Path::new(output_file_path);
         let output_dir = output_path.parent().unwrap_or(Path::new(""));
 
         // Group interfaces by source file
         let mut imports_by_file: HashMap<String, HashSet<String>> = HashMap::new();
+        let mut value_imports_by_file: HashMap<String, HashSet<String>> = HashMap::new();
+        let mut imports = Vec::new();
 
         // Collect all referenced types
         let mut referenced_types = HashSet::new();
-        for (_, interface) in validators {
-            referenced_types.insert(interface.name.clone());
+        for validator in validators {
+            referenced_types.insert(validator.interface_name.clone());
 
             // Check properties for referenced types
-            for prop in &interface.properties {
-                self.collect_referenced_types(&prop.type_annotation, &mut referenced_types);
+            self.collect_target_types(&validator.interface_name, &mut referenced_types);
+
+            // Constraints of type parameters are written into the signature
+            for param in self.named_type_parameters(&validator.interface_name) {
+                if let Some(constraint) = &param.constraint {
+                    self.collect_named_references(constraint, &mut referenced_types);
+                }
             }
         }
+        referenced_types.extend(self.predicate_references.borrow().iter().cloned());
 
         // Group interfaces by their source files
         for interface_name in &referenced_types {
-            if let Some(interface) = self.interfaces.get(interface_name) {
-                let source_path = Path::new(&interface.file_path);
+            // Global types are not imported
+            if let Some(file_path) = self
+                .type_file_path(interface_name)
+                .filter(|file_path| !file_path.is_empty())
+            {
+                let source_path = Path::new(file_path);
                 let source_path_str = source_path.to_string_lossy();
 
                 // Check if this is an external package or a local file
-                let import_path = if source_path_str.contains("node_modules") {
+                let import_path = if !source_path.exists()
+                    && !file_path.starts_with('.')
+                    && !source_path.is_absolute()
+                {
+                    // Types of ambient modules such as `declare module 'pkg'`
+                    // are imported from the package
+                    file_path.to_string()
+                } else if source_path_str.contains("node_modules") {
                     // For node_modules, extract the package import path
                     // Look for pattern like "node_modules/package-name/path/to/file"
                     if let Some(pos) = source_path_str.find("node_modules/") {
@@ -108,8 +343,8 @@ impl ValidatorGenerator {
                         let cleaned = after_node_modules.replace("/./", "/");
 
                         // Remove file extension (.d.ts, .ts, .js, etc)
-                        let without_ext = if cleaned.ends_with(".d.ts") {
-                            &cleaned[..cleaned.len() - 5]
+                        let without_ext = if let Some(stripped) = cleaned.strip_suffix(".d.ts") {
+                            stripped
                         } else if let Some(dot_pos) = cleaned.rfind('.') {
                             &cleaned[..dot_pos]
                         } else {
@@ -146,13 +381,9 @@ impl ValidatorGenerator {
                         let path_str = source_path_str.replace('\\', "/");
 
                         // Find package name by looking for common monorepo patterns
-                        if let Some(package_match) = path_str.split('/').rev().find_map(|part| {
+                        if let Some(package_match) = path_str.split('/').rev().find(|part| {
                             // Look for user-messaging-types or similar package names
-                            if part.contains("-types") || part.contains("-api") {
-                                Some(part)
-                            } else {
-                                None
-                            }
+                            part.contains("-types") || part.contains("-api")
                         }) {
                             // Found a package name, now extract the path within the package
                             if let Some(package_pos) =
@@ -167,13 +398,14 @@ impl ValidatorGenerator {
                                     clean_path = &clean_path[4..];
                                 }
 
-                                let without_ext = if clean_path.ends_with(".d.ts") {
-                                    &clean_path[..clean_path.len() - 5]
-                                } else if let Some(dot_pos) = clean_path.rfind('.') {
-                                    &clean_path[..dot_pos]
-                                } else {
-                                    clean_path
-                                };
+                                let without_ext =
+                                    if let Some(stripped) = clean_path.strip_suffix(".d.ts") {
+                                        stripped
+                                    } else if let Some(dot_pos) = clean_path.rfind('.') {
+                                        &clean_path[..dot_pos]
+                                    } else {
+                                        clean_path
+                                    };
 
                                 // Add .js extension if configured
                                 let import_specifier = if self.use_js_extensions {
@@ -197,15 +429,64 @@ impl ValidatorGenerator {
                     }
                 };
 
+                // Members of namespaces are imported with their namespace, as
+                // `Api` for `Api.Order`
+                let declared_name = self.declared_name(interface_name);
+                let (local_name, declared_name) = match interface_name.split_once('.') {
+                    Some((namespace, _)) => (
+                        namespace,
+                        declared_name.split('.').next().unwrap_or(namespace),
+                    ),
+                    None => (interface_name.as_str(), declared_name),
+                };
+
+                // Classes checked with `instanceof` are needed at runtime
+                let is_value = self.is_nominal_class(interface_name);
+                let keyword = if is_value { "" } else { "type " };
+                // Types are imported by the name their module exports them as,
+                // and types sharing a name under the names they are recorded
+                // as, such as `User as User2`
+                let export_name = self
+                    .export_names
+                    .get(local_name)
+                    .map(String::as_str)
+                    .unwrap_or(declared_name);
+                match export_name {
+                    // Modules declared with `export = Api` export the members of `Api`
+                    "=" if local_name != interface_name => {
+                        imports.push(format!(
+                            "import {}* as {} from '{}';",
+                            keyword, local_name, import_path
+                        ));
+                        continue;
+                    }
+                    "default" | "=" => {
+                        imports.push(format!(
+                            "import {}{} from '{}';",
+                            keyword, local_name, import_path
+                        ));
+                        continue;
+                    }
+                    _ => {}
+                }
+                let specifier = if export_name == local_name {
+                    local_name.to_string()
+                } else {
+                    format!("{} as {}", export_name, local_name)
+                };
+                let imports_by_file = if is_value {
+                    &mut value_imports_by_file
+                } else {
+                    &mut imports_by_file
+                };
                 imports_by_file
                     .entry(import_path)
-                    .or_insert_with(HashSet::new)
-                    .insert(interface_name.clone());
+                    .or_default()
+                    .insert(specifier);
             }
         }
 
         // Generate import statements
-        let mut imports = Vec::new();
         for (file, types) in imports_by_file {
             let mut type_list: Vec<_> = types.into_iter().collect();
             type_list.sort();
@@ -215,14 +496,32 @@ impl ValidatorGenerator {
                 file
             ));
         }
+        for (file, classes) in value_imports_by_file {
+            let mut class_list: Vec<_> = classes.into_iter().collect();
+            class_list.sort();
+            imports.push(format!(
+                "import {{ {} }} from '{}';",
+                class_list.join(", "),
+                file
+            ));
+        }
         imports.sort();
+        imports.dedup();
 
         imports.join("\n")
     }
 
     fn calculate_relative_import_path(&self, source_path: &Path, output_dir: &Path) -> String {
+        // Modules resolved from other directories, such as those augmented by
+        // type files, have absolute paths
+        let output_dir = if source_path.is_absolute() && !output_dir.is_absolute() {
+            std::env::current_dir().unwrap_or_default().join(output_dir)
+        } else {
+            output_dir.to_path_buf()
+        };
+
         // Calculate relative path from output file to source file
-        let relative_path = if let Some(rel) = pathdiff::diff_paths(source_path, output_dir) {
+        let relative_path = if let Some(rel) = pathdiff::diff_paths(source_path, &output_dir) {
             rel
         } else {
             source_path.to_path_buf()
@@ -232,7 +531,9 @@ impl ValidatorGenerator {
         let mut import_path = relative_path.to_string_lossy().replace('\\', "/");
 
         // Remove extension (.ts, .tsx, .d.ts, etc)
-        if let Some(pos) = import_path.rfind('.') {
+        if let Some(stripped) = import_path.strip_suffix(".d.ts") {
+            import_path = stripped.to_string();
+        } else if let Some(pos) = import_path.rfind('.') {
             import_path = import_path[..pos].to_string();
         } else {
             import_path = import_path.to_string();
@@ -252,56 +553,277 @@ impl ValidatorGenerator {
     }
 
     fn collect_referenced_types(&self, type_str: &str, referenced_types: &mut HashSet<String>) {
-        if type_str.ends_with("[]") {
-            let element_type = &type_str[..type_str.len() - 2];
+        let type_str = strip_parens(type_str);
+        if let Some(evaluated) = self.evaluate_type_operator(type_str) {
+            return self.collect_referenced_types(&evaluated, referenced_types);
+        }
+        let members = union_members(type_str);
+
+        if members.len() > 1 {
+            for t in members {
+                self.collect_referenced_types(t, referenced_types);
+            }
+        } else if let Some(element_type) = array_element_type(type_str) {
             self.collect_referenced_types(element_type, referenced_types);
-        } else if type_str.contains(" | ") {
-            for t in type_str.split(" | ") {
-                if !t.starts_with('\'') {
-                    self.collect_referenced_types(t, referenced_types);
+        } else if let Some(elements) = tuple_elements(type_str) {
+            for element in elements {
+                self.collect_referenced_types(element.type_str(), referenced_types);
+            }
+        } else if intersection_members(type_str).len() > 1 {
+            for t in intersection_members(type_str) {
+                self.collect_referenced_types(t, referenced_types);
+            }
+        } else if let Some(properties) = object_literal_properties(type_str) {
+            for prop in properties {
+                self.collect_referenced_types(&prop.type_annotation, referenced_types);
+            }
+        } else if let Some((type_name, type_arguments)) = split_type_reference(type_str) {
+            if self.is_named_type(type_name) {
+                referenced_types.insert(type_name.to_string());
+            } else if matches!(type_name, "Partial" | "Required" | "Pick" | "Omit") {
+                // Utility types are validated inline, so only the types of their
+                // properties are referenced
+                if let Some(properties) = self.resolve_type_properties(type_str) {
+                    for prop in properties {
+                        self.collect_referenced_types(&prop.type_annotation, referenced_types);
+                    }
+                    return;
                 }
             }
-        } else if self.interfaces.contains_key(type_str) {
+            for arg in type_arguments {
+                self.collect_referenced_types(arg, referenced_types);
+            }
+        } else if self.is_named_type(type_str) {
             referenced_types.insert(type_str.to_string());
         }
     }
 
     fn generate_validator(&self, function_name: &str, interface: &InterfaceInfo) -> String {
+        if self.is_nominal_class(&interface.name) {
+            let mut body =
+                validator_signature(function_name, &interface.name, &interface.type_parameters);
+            body.push_str(&format!("  return value instanceof {};\n", interface.name));
+            body.push('}');
+            return body;
+        }
+
+        self.generate_object_validator(
+            function_name,
+            &interface.name,
+            &interface.type_parameters,
+            &self.resolve_properties(interface),
+            self.is_callable(interface, &mut HashSet::new()),
+        )
+    }
+
+    /// Whether a class listed in `nominalClasses`, which is checked with
+    /// `instanceof` rather than by its public members
+    fn is_nominal_class(&self, type_name: &str) -> bool {
+        self.interfaces.get(type_name).is_some_and(|interface| {
+            interface.is_class && self.nominal_classes.contains(&interface.declared_name)
+        })
+    }
+
+    /// Whether an interface or one of the interfaces it extends has call or
+    /// construct signatures
+    fn is_callable(&self, interface: &InterfaceInfo, visiting: &mut HashSet<String>) -> bool {
+        if interface.callable {
+            return true;
+        }
+        if !visiting.insert(interface.name.clone()) {
+            return false;
+        }
+        interface.extends.iter().any(|base_type| {
+            let (base_name, _) =
+                split_type_reference(base_type).unwrap_or((base_type.as_str(), Vec::new()));
+            self.interfaces
+                .get(base_name)
+                .is_some_and(|base| self.is_callable(base, visiting))
+        })
+    }
+
+    fn generate_object_validator(
+        &self,
+        function_name: &str,
+        type_name: &str,
+        type_parameters: &[TypeParameterInfo],
+        properties: &[PropertyInfo],
+        callable: bool,
+    ) -> String {
         let mut body = String::new();
 
-        body.push_str(&format!(
-            "export function {}(value: unknown): value is {} {{\n",
-            function_name, interface.name
+        body.push_str(&validator_signature(
+            function_name,
+            type_name,
+            type_parameters,
         ));
-        body.push_str("  if (typeof value !== 'object' || value === null) {\n");
+        if callable {
+            // Values of interfaces with call signatures are functions
+            body.push_str("  if (typeof value !== 'function') {\n");
+        } else {
+            body.push_str("  if (typeof value !== 'object' || value === null) {\n");
+        }
         body.push_str("    return false;\n");
         body.push_str("  }\n\n");
-        body.push_str(&format!("  const obj = value as {};\n", interface.name));
+        body.push_str(&format!(
+            "  const obj = value as {};\n",
+            guarded_type_name(type_name, type_parameters)
+        ));
 
-        for prop in &interface.properties {
-            body.push_str(&self.generate_property_check(prop));
+        let type_parameters = type_parameter_names(type_parameters);
+        for prop in properties {
+            body.push_str(&self.generate_property_check(prop, &type_parameters));
         }
 
         body.push_str("  return true;\n");
-        body.push_str("}");
+        body.push('}');
+
+        body
+    }
+
+    fn generate_type_alias_validator(&self, function_name: &str, alias: &TypeAliasInfo) -> String {
+        // Aliases of object types derived from other types, such as intersections
+        // or `Partial<User>`, are validated property by property like interfaces
+        let (type_name, _) = split_type_reference(&alias.type_annotation)
+            .unwrap_or((alias.type_annotation.as_str(), Vec::new()));
+        if !self.is_named_type(type_name) {
+            if let Some(properties) = self.resolve_type_properties(&alias.type_annotation) {
+                return self.generate_object_validator(
+                    function_name,
+                    &alias.name,
+                    &alias.type_parameters,
+                    &properties,
+                    false,
+                );
+            }
+        }
+
+        let mut body = String::new();
+        let type_parameters = type_parameter_names(&alias.type_parameters);
+
+        body.push_str(&validator_signature(
+            function_name,
+            &alias.name,
+            &alias.type_parameters,
+        ));
+
+        let union_types = union_members(&alias.type_annotation);
+        if let Some(discriminant) = self.find_discriminant(&union_types) {
+            // Dispatch on the discriminant so only the matching member is validated
+            body.push_str(&format!(
+                "  if (typeof value !== 'object' || value === null || !({} in value)) {{\n",
+                quote_string(&discriminant.property)
+            ));
+            body.push_str("    return false;\n");
+            body.push_str("  }\n\n");
+            body.push_str(&format!(
+                "  switch ({}) {{\n",
+                property_access("value", &discriminant.property)
+            ));
+            for (tags, member) in &discriminant.cases {
+                for tag in tags {
+                    body.push_str(&format!("    case {}:\n", tag));
+                }
+                body.push_str(&format!(
+                    "      return {};\n",
+                    self.get_inline_validation(member, "value", &type_parameters)
+                ));
+            }
+            body.push_str("    default:\n");
+            body.push_str("      return false;\n");
+            body.push_str("  }\n");
+        } else {
+            body.push_str(&format!(
+                "  return {};\n",
+                self.get_inline_validation(&alias.type_annotation, "value", &type_parameters)
+            ));
+        }
+        body.push('}');
+
+        body
+    }
+
+    fn generate_enum_validator(
+        &self,
+        function_name: &str,
+        enum_name: &str,
+        enum_info: &EnumInfo,
+    ) -> String {
+        // `value` is unknown, so it must be narrowed before comparing it with a range
+        let checks = enum_member_checks(enum_info, "value")
+            .into_iter()
+            .map(|check| match check {
+                EnumCheck::Range(range) => {
+                    EnumCheck::Range(format!("typeof value === 'number' && {}", range))
+                }
+                check => check,
+            })
+            .collect();
+
+        let mut body = validator_signature(function_name, enum_name, &[]);
+        body.push_str(&format!(
+            "  return {};\n",
+            self.join_enum_checks(checks, "value")
+        ));
+        body.push('}');
 
         body
     }
 
-    fn generate_property_check(&self, prop: &PropertyInfo) -> String {
+    fn generate_property_check(&self, prop: &PropertyInfo, type_parameters: &[String]) -> String {
         let mut check = String::new();
+
+        if let Some(key_type) = index_signature_key(&prop.name) {
+            let validation = self.get_index_signature_validation(
+                key_type,
+                &prop.type_annotation,
+                "obj",
+                type_parameters,
+            );
+            if validation != "true" {
+                check.push_str(&format!("  if (!{}) {{\n", validation));
+                check.push_str("    return false;\n");
+                check.push_str("  }\n");
+            }
+            return check;
+        }
+
+        let prop_expr = property_access("obj", &prop.name);
+
+        // Properties accepting any value only need to be present when required
+        if matches!(strip_parens(&prop.type_annotation), "any" | "unknown") {
+            if !prop.optional {
+                check.push_str(&format!(
+                    "  if (!({} in obj)) {{\n",
+                    quote_string(&prop.name)
+                ));
+                check.push_str("    return false;\n");
+                check.push_str("  }\n");
+            }
+            return check;
+        }
+
         let validation =
-            self.get_inline_validation(&prop.type_annotation, &format!("obj.{}", prop.name));
+            || self.get_inline_validation(&prop.type_annotation, &prop_expr, type_parameters);
 
         if prop.optional {
             // For optional properties, combine the undefined check with type validation
             let needs_complex_check = !matches!(
                 prop.type_annotation.as_str(),
-                "string" | "number" | "boolean" | "null" | "undefined"
+                "string"
+                    | "number"
+                    | "boolean"
+                    | "bigint"
+                    | "symbol"
+                    | "Function"
+                    | "never"
+                    | "null"
+                    | "undefined"
             ) && !prop.type_annotation.starts_with('\'')
-                && !prop.type_annotation.contains(" | ");
+                && union_members(&prop.type_annotation).len() == 1;
             if needs_complex_check {
                 // Check if validation already has outer parentheses
+                let validation = validation();
                 let wrapped_validation = if validation.starts_with('(') && validation.ends_with(')')
                 {
                     format!("!{}", validation)
@@ -309,25 +831,31 @@ impl ValidatorGenerator {
                     format!("!({})", validation)
                 };
                 check.push_str(&format!(
-                    "  if (obj.{} !== undefined && {}) {{\n",
-                    prop.name, wrapped_validation
+                    "  if ({} !== undefined && {}) {{\n",
+                    prop_expr, wrapped_validation
                 ));
             } else {
-                let negated_validation = self
-                    .get_negated_validation(&prop.type_annotation, &format!("obj.{}", prop.name));
-                check.push_str(&format!(
-                    "  if (obj.{} !== undefined && {}) {{\n",
-                    prop.name, negated_validation
-                ));
+                let negated_validation =
+                    self.get_negated_validation(&prop.type_annotation, &prop_expr, type_parameters);
+                if negated_validation == "true" {
+                    // An optional `never` property must be absent
+                    check.push_str(&format!("  if ({} !== undefined) {{\n", prop_expr));
+                } else {
+                    check.push_str(&format!(
+                        "  if ({} !== undefined && {}) {{\n",
+                        prop_expr, negated_validation
+                    ));
+                }
             }
             check.push_str("    return false;\n");
             check.push_str("  }\n");
         } else {
             // For required properties, we can skip the 'in' check if the type check would fail for undefined
-            let needs_in_check = self.type_allows_undefined(&prop.type_annotation);
+            let needs_in_check = self.type_allows_undefined(&prop.type_annotation, type_parameters);
 
             if needs_in_check {
                 // Check if validation already has outer parentheses
+                let validation = validation();
                 let wrapped_validation = if validation.starts_with('(') && validation.ends_with(')')
                 {
                     format!("!{}", validation)
@@ -335,13 +863,14 @@ impl ValidatorGenerator {
                     format!("!({})", validation)
                 };
                 check.push_str(&format!(
-                    "  if (!('{}' in obj) || {}) {{\n",
-                    prop.name, wrapped_validation
+                    "  if (!({} in obj) || {}) {{\n",
+                    quote_string(&prop.name),
+                    wrapped_validation
                 ));
             } else {
                 // For simple type checks, we can negate directly
-                let negated_validation = self
-                    .get_negated_validation(&prop.type_annotation, &format!("obj.{}", prop.name));
+                let negated_validation =
+                    self.get_negated_validation(&prop.type_annotation, &prop_expr, type_parameters);
                 check.push_str(&format!("  if ({}) {{\n", negated_validation));
             }
             check.push_str("    return false;\n");
@@ -351,47 +880,105 @@ impl ValidatorGenerator {
         check
     }
 
-    fn get_inline_validation(&self, type_str: &str, value_expr: &str) -> String {
+    fn get_inline_validation(
+        &self,
+        type_str: &str,
+        value_expr: &str,
+        type_parameters: &[String],
+    ) -> String {
+        let type_str = strip_parens(type_str);
+        let union_types = union_members(type_str);
+
+        if let Some(evaluated) = self.evaluate_type_operator(type_str) {
+            return self.get_inline_validation(&evaluated, value_expr, type_parameters);
+        }
+
+        if let Some(element_type) = array_element_type(type_str) {
+            return format!(
+                "(Array.isArray({}) && {}.every({}))",
+                value_expr,
+                value_expr,
+                self.get_element_check(element_type, type_parameters)
+            );
+        }
+
+        if let Some(elements) = tuple_elements(type_str) {
+            return self.get_tuple_validation(&elements, value_expr, type_parameters);
+        }
+
+        if intersection_members(type_str).len() > 1 {
+            return self.get_intersection_validation(type_str, value_expr, type_parameters);
+        }
+
+        if let Some((quasis, types)) = template_literal_parts(type_str) {
+            return self.get_template_literal_validation(&quasis, &types, value_expr);
+        }
+
+        if let Some(properties) = object_literal_properties(type_str) {
+            if properties.is_empty() {
+                // `{}` accepts any value other than null and undefined
+                return format!("({} !== null && {} !== undefined)", value_expr, value_expr);
+            }
+            return self.get_object_validation(&properties, value_expr, type_parameters);
+        }
+
         match type_str {
             "string" => format!("typeof {} === 'string'", value_expr),
             "number" => format!("typeof {} === 'number'", value_expr),
             "boolean" => format!("typeof {} === 'boolean'", value_expr),
-            "any" => "true".to_string(),
+            "bigint" => format!("typeof {} === 'bigint'", value_expr),
+            "symbol" => format!("typeof {} === 'symbol'", value_expr),
+            "Function" => format!("typeof {} === 'function'", value_expr),
+            "object" => format!(
+                "((typeof {} === 'object' && {} !== null) || typeof {} === 'function')",
+                value_expr, value_expr, value_expr
+            ),
+            "any" | "unknown" => "true".to_string(),
+            "never" => "false".to_string(),
             "void" => format!("{} === undefined", value_expr),
             "null" => format!("{} === null", value_expr),
             "undefined" => format!("{} === undefined", value_expr),
-            _ if type_str.ends_with("[]") => {
-                let element_type = &type_str[..type_str.len() - 2];
-                if self.is_simple_type(element_type) {
-                    let element_check = self.get_simple_type_check(element_type);
-                    format!(
-                        "(Array.isArray({}) && {}.every({}))",
-                        value_expr, value_expr, element_check
-                    )
-                } else {
-                    format!(
-                        "(Array.isArray({}) && {}.every(validate{}))",
-                        value_expr, value_expr, element_type
-                    )
-                }
-            }
-            _ if type_str.contains(" | ") => {
-                let types: Vec<&str> = type_str.split(" | ").collect();
-                let checks: Vec<String> = types
+            _ if union_types.len() > 1 => {
+                // Object members sharing a discriminant are checked together by
+                // selecting the member validator from the discriminant's value
+                let object_types: Vec<&str> = union_types
                     .iter()
+                    .copied()
+                    .filter(|t| self.resolve_type_properties(t).is_some())
+                    .collect();
+                let discriminant = self.find_discriminant(&object_types);
+
+                let mut checks: Vec<String> = union_types
+                    .iter()
+                    .filter(|t| discriminant.is_none() || !object_types.contains(t))
                     .map(|t| {
-                        if t.starts_with('\'') && t.ends_with('\'') {
+                        if is_literal_type(t) {
                             format!("{} === {}", value_expr, t)
                         } else {
-                            self.get_inline_validation(t, value_expr)
+                            self.get_inline_validation(t, value_expr, type_parameters)
                         }
                     })
                     .collect();
+                if let Some(discriminant) = discriminant {
+                    checks.push(self.get_discriminated_validation(
+                        &discriminant,
+                        value_expr,
+                        type_parameters,
+                    ));
+                }
+                // A union with a member accepting any value accepts any value
+                if checks.iter().any(|check| check == "true") {
+                    return "true".to_string();
+                }
                 format!("({})", checks.join(" || "))
             }
-            _ if type_str.starts_with('\'') && type_str.ends_with('\'') => {
+            _ if is_literal_type(type_str) => {
                 format!("({} === {})", value_expr, type_str)
             }
+            _ if type_parameters.iter().any(|param| param == type_str) => {
+                // Type parameters are validated by the validator passed for them
+                format!("validate{}({})", type_str, value_expr)
+            }
             _ => {
                 // Check for known built-in types
                 match type_str {
@@ -401,14 +988,56 @@ impl ValidatorGenerator {
                         value_expr, value_expr
                     ),
                     _ => {
-                        // Check for generic types like Record<K, V>
-                        if type_str.starts_with("Record<") && type_str.ends_with(">") {
-                            format!(
-                                "typeof {} === 'object' && {} !== null",
-                                value_expr, value_expr
-                            )
-                        } else if self.interfaces.contains_key(type_str) {
-                            format!("validate{}({})", type_str, value_expr)
+                        if let Some((type_name, type_arguments)) = split_type_reference(type_str) {
+                            match (type_name, type_arguments.as_slice()) {
+                                // Records are checked like objects with an index signature
+                                ("Record", [key_type, value_type]) => self.get_object_validation(
+                                    &self.record_properties(key_type, value_type),
+                                    value_expr,
+                                    type_parameters,
+                                ),
+                                ("Array" | "ReadonlyArray", [element_type]) => self
+                                    .get_inline_validation(
+                                        &format!("({})[]", element_type),
+                                        value_expr,
+                                        type_parameters,
+                                    ),
+                                _ if self.is_named_type(type_name) => self.get_generic_validation(
+                                    type_name,
+                                    &type_arguments,
+                                    value_expr,
+                                    type_parameters,
+                                ),
+                                // Readonly types have the same runtime shape as the wrapped type
+                                ("Readonly", [inner_type]) => self.get_inline_validation(
+                                    inner_type,
+                                    value_expr,
+                                    type_parameters,
+                                ),
+                                ("NonNullable", [inner_type]) => self.get_non_nullable_validation(
+                                    inner_type,
+                                    value_expr,
+                                    type_parameters,
+                                ),
+                                ("Partial" | "Required" | "Pick" | "Omit", _) => {
+                                    match self.resolve_type_properties(type_str) {
+                                        Some(properties) => self.get_object_validation(
+                                            &properties,
+                                            value_expr,
+                                            type_parameters,
+                                        ),
+                                        // The properties of type parameters are not known
+                                        None => format!(
+                                            "(typeof {} === 'object' && {} !== null)",
+                                            value_expr, value_expr
+                                        ),
+                                    }
+                                }
+                                // Unknown generic type - be conservative and check it's not undefined
+                                _ => format!("{} !== undefined", value_expr),
+                            }
+                        } else if self.is_named_type(type_str) {
+                            self.get_generic_validation(type_str, &[], value_expr, type_parameters)
                         } else if let Some(enum_info) = self.enums.get(type_str) {
                             // Generate enum validation
                             self.generate_enum_validation(enum_info, value_expr)
@@ -422,132 +1051,852 @@ impl ValidatorGenerator {
         }
     }
 
-    fn generate_enum_validation_negated(&self, enum_info: &EnumInfo, value_expr: &str) -> String {
-        // Generate validation that returns true when the value is NOT a valid enum member
-        let mut numeric_values: Vec<i64> = Vec::new();
-        let mut string_checks: Vec<String> = Vec::new();
-
-        for member in &enum_info.members {
-            match &member.value {
-                EnumValue::Number(n) => {
-                    if n.fract() == 0.0 {
-                        numeric_values.push(*n as i64);
-                    }
-                }
-                EnumValue::String(s) => {
-                    string_checks.push(format!("{} !== '{}'", value_expr, s));
-                }
-                EnumValue::Computed => {}
-            }
-        }
-
-        let mut checks: Vec<String> = Vec::new();
-
-        // Handle numeric values
-        if !numeric_values.is_empty() {
-            numeric_values.sort();
-
-            let mut i = 0;
-            while i < numeric_values.len() {
-                let start = numeric_values[i];
-                let mut end = start;
-
-                // Find consecutive sequence
-                while i + 1 < numeric_values.len() && numeric_values[i + 1] == end + 1 {
-                    end = numeric_values[i + 1];
-                    i += 1;
+    /// Checks a string against a template literal type such as `` `user_${string}` ``,
+    /// using prefix and suffix checks where possible and a regular expression otherwise
+    fn get_template_literal_validation(
+        &self,
+        quasis: &[String],
+        types: &[&str],
+        value_expr: &str,
+    ) -> String {
+        let string_check = format!("typeof {} === 'string'", value_expr);
+
+        match types {
+            [] => format!("({} === {})", value_expr, quote_string(&quasis[0])),
+            ["string"] => {
+                let (prefix, suffix) = (&quasis[0], &quasis[1]);
+                let mut checks = vec![string_check];
+                if !prefix.is_empty() && !suffix.is_empty() {
+                    checks.push(format!(
+                        "{}.length >= {}",
+                        value_expr,
+                        prefix.chars().count() + suffix.chars().count()
+                    ));
                 }
-
-                // Use range if we have 3 or more consecutive values
-                if end - start >= 2 {
-                    // Range checks don't need extra parentheses
+                if !prefix.is_empty() {
                     checks.push(format!(
-                        "{} < {} || {} > {}",
-                        value_expr, start, value_expr, end
+                        "{}.startsWith({})",
+                        value_expr,
+                        quote_string(prefix)
                     ));
-                } else {
-                    // Use individual checks for small ranges
-                    for val in start..=end {
-                        checks.push(format!("{} !== {}", value_expr, val));
-                    }
                 }
-
-                i += 1;
+                if !suffix.is_empty() {
+                    checks.push(format!("{}.endsWith({})", value_expr, quote_string(suffix)));
+                }
+                format!("({})", checks.join(" && "))
             }
+            _ => match self.template_literal_pattern(quasis, types) {
+                Some(pattern) => {
+                    format!("({} && /^{}$/.test({}))", string_check, pattern, value_expr)
+                }
+                None => {
+                    eprintln!(
+                        "Warning: Template literal type `{}` cannot be checked exactly, only checking it is a string",
+                        template_literal_string(quasis, types)
+                    );
+                    string_check
+                }
+            },
         }
+    }
 
-        // Add string checks
-        checks.extend(string_checks);
-
-        if checks.is_empty() {
-            format!("{} === undefined", value_expr)
-        } else if checks.len() == 1 {
-            checks[0].clone()
-        } else {
-            // Use AND because all conditions must be true for invalid value
-            // Join without wrapping in parentheses - they'll be added by the if statement
-            checks.join(" && ")
+    /// Builds a regular expression matching the strings of a template literal type
+    fn template_literal_pattern(&self, quasis: &[String], types: &[&str]) -> Option<String> {
+        let mut pattern = regex_escape(&quasis[0]);
+        for (type_str, quasi) in types.iter().zip(&quasis[1..]) {
+            pattern.push_str(&self.template_type_pattern(type_str)?);
+            pattern.push_str(&regex_escape(quasi));
         }
+        Some(pattern)
     }
 
-    fn generate_enum_validation(&self, enum_info: &EnumInfo, value_expr: &str) -> String {
-        // Collect numeric and string values separately
-        let mut numeric_values: Vec<i64> = Vec::new();
-        let mut string_values: Vec<String> = Vec::new();
-
-        for member in &enum_info.members {
-            match &member.value {
-                EnumValue::Number(n) => {
-                    // Convert to i64 for integer comparison
-                    if n.fract() == 0.0 {
-                        numeric_values.push(*n as i64);
-                    }
+    /// The regular expression for a type interpolated into a template literal type
+    fn template_type_pattern(&self, type_str: &str) -> Option<String> {
+        let type_str = strip_parens(type_str);
+        let union_types = union_members(type_str);
+        if union_types.len() > 1 {
+            let patterns: Option<Vec<String>> = union_types
+                .iter()
+                .map(|t| self.template_type_pattern(t))
+                .collect();
+            return Some(format!("({})", patterns?.join("|")));
+        }
+
+        match type_str {
+            "string" => Some(r"[\s\S]*".to_string()),
+            "number" => Some(r"[+-]?(\d+\.?\d*|\.\d+)([eE][+-]?\d+)?".to_string()),
+            "bigint" => Some(r"-?\d+".to_string()),
+            "boolean" => Some("(true|false)".to_string()),
+            "null" | "undefined" | "true" | "false" => Some(type_str.to_string()),
+            _ if type_str.starts_with('\'') && type_str.ends_with('\'') => {
+                unquote_string(type_str).map(|text| regex_escape(&text))
+            }
+            _ if type_str.parse::<f64>().is_ok() => Some(regex_escape(type_str)),
+            _ => {
+                if let Some((quasis, types)) = template_literal_parts(type_str) {
+                    return self.template_literal_pattern(&quasis, &types);
                 }
-                EnumValue::String(s) => {
-                    string_values.push(format!("{} === '{}'", value_expr, s));
+                match self.type_aliases.get(type_str) {
+                    Some(alias) if alias.type_parameters.is_empty() => {
+                        self.template_type_pattern(&alias.type_annotation)
+                    }
+                    _ => None,
                 }
-                EnumValue::Computed => {} // Skip computed values
             }
         }
+    }
 
-        let mut checks: Vec<String> = Vec::new();
+    /// Finds a required property with literal types that differ between all the
+    /// given object types, such as `kind` in `Circle | Square`
+    fn find_discriminant<'a>(&self, types: &[&'a str]) -> Option<Discriminant<'a>> {
+        if types.len() < 2 {
+            return None;
+        }
 
-        // Handle numeric values - look for consecutive ranges
-        if !numeric_values.is_empty() {
-            numeric_values.sort();
+        let member_properties: Vec<Vec<PropertyInfo>> = types
+            .iter()
+            .map(|t| self.resolve_type_properties(t))
+            .collect::<Option<_>>()?;
 
-            let mut i = 0;
-            while i < numeric_values.len() {
-                let start = numeric_values[i];
-                let mut end = start;
+        member_properties[0].iter().find_map(|candidate| {
+            let mut cases = Vec::new();
+            let mut seen_tags: Vec<String> = Vec::new();
 
-                // Find consecutive sequence
-                while i + 1 < numeric_values.len() && numeric_values[i + 1] == end + 1 {
-                    end = numeric_values[i + 1];
-                    i += 1;
+            for (properties, member) in member_properties.iter().zip(types) {
+                let prop = properties
+                    .iter()
+                    .find(|p| p.name == candidate.name && !p.optional)?;
+                // Enum members such as `Kind.Click` are evaluated to their values
+                let tags: Vec<String> = union_members(&prop.type_annotation)
+                    .into_iter()
+                    .map(|tag| {
+                        self.evaluate_type_operator(tag)
+                            .unwrap_or_else(|| tag.to_string())
+                    })
+                    .collect();
+                if !tags.iter().all(|tag| is_literal_type(tag))
+                    || tags.iter().any(|tag| seen_tags.contains(tag))
+                {
+                    return None;
                 }
+                seen_tags.extend(tags.iter().cloned());
+                cases.push((tags, *member));
+            }
 
-                // Use range if we have 3 or more consecutive values
-                if end - start >= 2 {
-                    checks.push(format!(
-                        "{} >= {} && {} <= {}",
-                        value_expr, start, value_expr, end
-                    ));
-                } else {
-                    // Use individual checks for small ranges
-                    for val in start..=end {
-                        checks.push(format!("{} === {}", value_expr, val));
-                    }
-                }
+            Some(Discriminant {
+                property: candidate.name.clone(),
+                cases,
+            })
+        })
+    }
 
-                i += 1;
-            }
+    /// Inline form of a discriminated union check, choosing the member to
+    /// validate with a conditional on the discriminant
+    fn get_discriminated_validation(
+        &self,
+        discriminant: &Discriminant,
+        value_expr: &str,
+        type_parameters: &[String],
+    ) -> String {
+        let tag_expr = property_access(value_expr, &discriminant.property);
+        let mut dispatch = "false".to_string();
+
+        for (tags, member) in discriminant.cases.iter().rev() {
+            let condition: Vec<String> = tags
+                .iter()
+                .map(|tag| format!("{} === {}", tag_expr, tag))
+                .collect();
+            dispatch = format!(
+                "{} ? {} : {}",
+                condition.join(" || "),
+                self.get_inline_validation(member, value_expr, type_parameters),
+                dispatch
+            );
         }
 
-        // Add string value checks
-        checks.extend(string_values);
+        format!(
+            "(typeof {} === 'object' && {} !== null && {} in {} && ({}))",
+            value_expr,
+            value_expr,
+            quote_string(&discriminant.property),
+            value_expr,
+            dispatch
+        )
+    }
 
-        if checks.is_empty() {
+    /// Validates an intersection of object types as a single object with the
+    /// combined properties, otherwise every member of the intersection is checked
+    fn get_intersection_validation(
+        &self,
+        type_str: &str,
+        value_expr: &str,
+        type_parameters: &[String],
+    ) -> String {
+        if let Some(properties) = self.resolve_type_properties(type_str) {
+            return self.get_object_validation(&properties, value_expr, type_parameters);
+        }
+
+        let members = intersection_members(type_str);
+
+        // Branded primitives such as `string & { __brand: 'UserId' }` only exist
+        // at the type level, so only the primitive part can be checked
+        let primitives: Vec<&str> = members
+            .iter()
+            .copied()
+            .filter(|t| primitive_kind(t).is_some())
+            .collect();
+        let checked_members = if primitives.is_empty() {
+            members
+        } else {
+            primitives
+        };
+
+        // Conflicting primitives such as `string & number` reduce to `never`
+        let conflicting = checked_members.iter().any(|a| {
+            checked_members.iter().any(|b| {
+                primitive_kind(a) != primitive_kind(b)
+                    || (is_literal_type(a) && is_literal_type(b) && a != b)
+            })
+        });
+        if conflicting {
+            eprintln!(
+                "Warning: Intersection '{}' has conflicting members and can never be satisfied",
+                type_str
+            );
+            return "false".to_string();
+        }
+
+        let checks: Vec<String> = checked_members
+            .iter()
+            .map(|t| self.get_inline_validation(t, value_expr, type_parameters))
+            .collect();
+        format!("({})", checks.join(" && "))
+    }
+
+    /// Checks an object type inline, using `in` checks so that each property
+    /// access is valid even when the value is not statically known to be an object
+    fn get_object_validation(
+        &self,
+        properties: &[PropertyInfo],
+        value_expr: &str,
+        type_parameters: &[String],
+    ) -> String {
+        let mut checks = vec![
+            format!("typeof {} === 'object'", value_expr),
+            format!("{} !== null", value_expr),
+        ];
+
+        for prop in properties {
+            if let Some(key_type) = index_signature_key(&prop.name) {
+                let validation = self.get_index_signature_validation(
+                    key_type,
+                    &prop.type_annotation,
+                    value_expr,
+                    type_parameters,
+                );
+                if validation != "true" {
+                    checks.push(validation);
+                }
+                continue;
+            }
+
+            let prop_expr = property_access(value_expr, &prop.name);
+            let validation =
+                self.get_inline_validation(&prop.type_annotation, &prop_expr, type_parameters);
+            if prop.optional {
+                if validation == "true" {
+                    continue;
+                }
+                checks.push(format!(
+                    "(!({} in {}) || {} === undefined || {})",
+                    quote_string(&prop.name),
+                    value_expr,
+                    prop_expr,
+                    validation
+                ));
+            } else {
+                checks.push(format!("{} in {}", quote_string(&prop.name), value_expr));
+                if validation != "true" {
+                    checks.push(validation);
+                }
+            }
+        }
+
+        format!("({})", checks.join(" && "))
+    }
+
+    /// Checks every value of an object against an index signature. Numeric index
+    /// signatures only apply to keys that are numbers, while symbol keys are not
+    /// enumerable through `Object.entries` and are not checked
+    fn get_index_signature_validation(
+        &self,
+        key_type: &str,
+        value_type: &str,
+        value_expr: &str,
+        type_parameters: &[String],
+    ) -> String {
+        if value_type == "any" || value_type == "unknown" {
+            return "true".to_string();
+        }
+
+        match key_type {
+            "string" => format!(
+                "(Object.values({}).every({}))",
+                value_expr,
+                self.get_element_check(value_type, type_parameters)
+            ),
+            "number" => format!(
+                "(Object.entries({}).every(([k, v]) => String(Number(k)) !== k || {}))",
+                value_expr,
+                self.get_inline_validation(value_type, "v", type_parameters)
+            ),
+            _ => "true".to_string(),
+        }
+    }
+
+    /// Returns the type an alias stands for, with its type arguments substituted
+    fn expand_alias(&self, type_str: &str) -> Option<String> {
+        let (type_name, type_arguments) =
+            split_type_reference(type_str).unwrap_or((type_str, Vec::new()));
+        let alias = self.type_aliases.get(type_name)?;
+        let substitutions = type_parameter_substitutions(&alias.type_parameters, &type_arguments);
+        Some(substitute_type_parameters(
+            &alias.type_annotation,
+            &substitutions,
+        ))
+    }
+
+    /// Returns the property names given as a union of string literals, as in
+    /// the keys of `Pick<User, 'id' | 'name'>`
+    fn literal_keys(&self, keys: &str) -> Option<Vec<String>> {
+        union_members(&self.resolve_key_type(keys))
+            .into_iter()
+            .map(|key| {
+                if let Some(name) = unquote_string(key) {
+                    Some(name)
+                } else if key.parse::<f64>().is_ok() {
+                    Some(key.to_string())
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Checks a value against `NonNullable<T>`, the members of `T` other than
+    /// `null` and `undefined`
+    fn get_non_nullable_validation(
+        &self,
+        type_str: &str,
+        value_expr: &str,
+        type_parameters: &[String],
+    ) -> String {
+        let expanded = self
+            .expand_alias(type_str)
+            .unwrap_or_else(|| type_str.to_string());
+        let members: Vec<&str> = union_members(&expanded)
+            .into_iter()
+            .filter(|t| !matches!(*t, "null" | "undefined" | "void"))
+            .collect();
+
+        match members.as_slice() {
+            [] => "false".to_string(),
+            ["any" | "unknown"] => self.get_inline_validation("{}", value_expr, type_parameters),
+            [member] if type_parameters.iter().any(|param| param == member) => format!(
+                "({} !== null && {} !== undefined && validate{}({}))",
+                value_expr, value_expr, member, value_expr
+            ),
+            _ => self.get_inline_validation(&members.join(" | "), value_expr, type_parameters),
+        }
+    }
+
+    /// Turns `Record<K, V>` into properties, where string and numeric literal
+    /// keys, including the values of enums, become required properties and any
+    /// other key becomes an index signature
+    fn record_properties(&self, key_type: &str, value_type: &str) -> Vec<PropertyInfo> {
+        let mut properties: Vec<PropertyInfo> = Vec::new();
+
+        for key in union_members(&self.resolve_key_type(key_type)) {
+            let name = match (unquote_string(key), key.parse::<f64>()) {
+                (Some(name), _) => name,
+                // Numeric keys are named as JavaScript converts them to strings
+                (None, Ok(n)) => format_number(n),
+                _ if key == "number" => "[key: number]".to_string(),
+                _ => "[key: string]".to_string(),
+            };
+
+            if !properties.iter().any(|p| p.name == name) {
+                properties.push(PropertyInfo {
+                    name,
+                    type_annotation: value_type.to_string(),
+                    optional: false,
+                });
+            }
+        }
+
+        properties
+    }
+
+    /// Follows non-generic type aliases and enums used as record keys, such as
+    /// `type Locale = 'en' | 'fr'`, to the union of keys they stand for
+    fn resolve_key_type(&self, key_type: &str) -> String {
+        let members: Vec<String> = union_members(key_type)
+            .into_iter()
+            .map(|member| {
+                if let Some(evaluated) = self.evaluate_type_operator(member) {
+                    return self.resolve_key_type(&evaluated);
+                }
+                if let Some(enum_info) = self.enums.get(member) {
+                    let values: Vec<String> = enum_info
+                        .members
+                        .iter()
+                        .filter_map(|m| self.enum_member_type(member, &m.name))
+                        .collect();
+                    return values.join(" | ");
+                }
+                match self.type_aliases.get(member) {
+                    Some(alias) if alias.type_parameters.is_empty() => {
+                        self.resolve_key_type(&alias.type_annotation)
+                    }
+                    _ => member.to_string(),
+                }
+            })
+            .collect();
+        members.join(" | ")
+    }
+
+    /// Evaluates types computed from other types, which are `typeof` queries of
+    /// constants, `keyof` and indexed access types such as `typeof Roles[number]`
+    fn evaluate_type_operator(&self, type_str: &str) -> Option<String> {
+        let type_str = strip_parens(type_str);
+        if union_members(type_str).len() > 1 || split_top_level(type_str, " & ").len() > 1 {
+            return None;
+        }
+
+        if let Some(resolved) = self.resolve_qualified_name(type_str) {
+            return Some(resolved);
+        }
+
+        if let Some(path) = type_str.strip_prefix("typeof ") {
+            // Leading namespaces such as `Config` in `typeof Config.roles` are skipped
+            let segments: Vec<&str> = path.split('.').collect();
+            let start = segments
+                .iter()
+                .position(|segment| self.constants.contains_key(*segment))?;
+            let mut result = self.constants[segments[start]].type_annotation.clone();
+            for key in &segments[start + 1..] {
+                result = self.indexed_access_type(&result, &quote_string(key))?;
+            }
+            return Some(result);
+        }
+
+        if let Some(operand) = type_str.strip_prefix("keyof ") {
+            let properties = self.resolve_type_properties(operand)?;
+            let mut keys: Vec<String> = Vec::new();
+            for prop in properties {
+                let key = match index_signature_key(&prop.name) {
+                    // String index signatures also accept numeric keys
+                    Some("string") => "string | number".to_string(),
+                    Some(key_type) => key_type.to_string(),
+                    None if prop.name.parse::<f64>().is_ok() => prop.name,
+                    None => quote_string(&prop.name),
+                };
+                if !keys.contains(&key) {
+                    keys.push(key);
+                }
+            }
+            return Some(if keys.is_empty() {
+                "never".to_string()
+            } else {
+                keys.join(" | ")
+            });
+        }
+
+        let (object_type, index_type) = indexed_access(type_str)?;
+        self.indexed_access_type(object_type, index_type)
+    }
+
+    /// Resolves a qualified name to the literal type of an enum member, such
+    /// as `'pending'` for `Status.Pending`, or to the named type, skipping
+    /// namespaces such as `Api` in `Api.Order`. Types declared in namespaces
+    /// are recorded under their qualified names and kept as they are, and
+    /// names qualified by unknown namespaces such as `Express.Request` are not
+    /// resolved, as they would otherwise be validated as a type that happens
+    /// to share their name
+    fn resolve_qualified_name(&self, type_str: &str) -> Option<String> {
+        let (qualifier, name) = type_str.rsplit_once('.')?;
+        if !qualifier.split('.').chain([name]).all(is_identifier)
+            || self.is_validator_target(type_str)
+        {
+            return None;
+        }
+        let enum_name = qualifier.rsplit('.').next().unwrap_or(qualifier);
+        self.enum_member_type(qualifier, name)
+            .or_else(|| self.enum_member_type(enum_name, name))
+            .or_else(|| self.is_namespace(qualifier).then(|| name.to_string()))
+    }
+
+    /// Whether types are declared in a namespace of the given name
+    fn is_namespace(&self, qualifier: &str) -> bool {
+        let prefix = format!("{}.", qualifier);
+        self.interfaces
+            .keys()
+            .chain(self.type_aliases.keys())
+            .chain(self.enums.keys())
+            .any(|key| key.starts_with(&prefix))
+    }
+
+    /// The literal type of an enum member such as `Status.Pending`. Computed
+    /// members can hold any number
+    fn enum_member_type(&self, enum_name: &str, member_name: &str) -> Option<String> {
+        let member = self
+            .enums
+            .get(enum_name)?
+            .members
+            .iter()
+            .find(|member| member.name == member_name)?;
+        Some(match &member.value {
+            EnumValue::String(s) => quote_string(s),
+            EnumValue::Number(n) if !n.is_nan() => format_number(*n),
+            EnumValue::Number(_) | EnumValue::Computed => "number".to_string(),
+        })
+    }
+
+    /// Evaluates `T[K]`, the union of the types of the properties or elements
+    /// of `T` selected by `K`
+    fn indexed_access_type(&self, object_type: &str, index_type: &str) -> Option<String> {
+        let object_type = self
+            .evaluate_type_operator(object_type)
+            .or_else(|| self.expand_alias(object_type))
+            .unwrap_or_else(|| strip_parens(object_type).to_string());
+
+        let mut member_types: Vec<String> = Vec::new();
+        for index in union_members(&self.resolve_key_type(index_type)) {
+            let member_type = if let Some(elements) = tuple_elements(&object_type) {
+                match index.parse::<usize>() {
+                    Ok(position) => elements.get(position)?.type_str().to_string(),
+                    Err(_) if index == "number" => {
+                        let element_types: Vec<&str> = elements
+                            .iter()
+                            .map(|element| match element {
+                                TupleElement::Rest(t) => array_element_type(t).unwrap_or("unknown"),
+                                _ => element.type_str(),
+                            })
+                            .collect();
+                        element_types.join(" | ")
+                    }
+                    Err(_) => return None,
+                }
+            } else if let Some(element_type) = array_element_type(&object_type) {
+                if index != "number" && index.parse::<f64>().is_err() {
+                    return None;
+                }
+                element_type.to_string()
+            } else {
+                let properties = self.resolve_type_properties(&object_type)?;
+                let name = unquote_string(index).unwrap_or_else(|| index.to_string());
+                let prop = properties.iter().find(|p| p.name == name).or_else(|| {
+                    properties
+                        .iter()
+                        .find(|p| p.name == format!("[key: {}]", index))
+                })?;
+                if prop.optional {
+                    format!("{} | undefined", prop.type_annotation)
+                } else {
+                    prop.type_annotation.clone()
+                }
+            };
+
+            for member in union_members(&member_type) {
+                if !member_types.iter().any(|t| t == member) {
+                    member_types.push(member.to_string());
+                }
+            }
+        }
+
+        Some(member_types.join(" | "))
+    }
+
+    /// Returns the combined properties of an object type, which may be an
+    /// interface, an object literal or an intersection of these
+    fn resolve_type_properties(&self, type_str: &str) -> Option<Vec<PropertyInfo>> {
+        let type_str = strip_parens(type_str);
+        if let Some(evaluated) = self.evaluate_type_operator(type_str) {
+            return self.resolve_type_properties(&evaluated);
+        }
+        let members = intersection_members(type_str);
+        if members.len() > 1 {
+            let mut properties = Vec::new();
+            for member in members {
+                for prop in self.resolve_type_properties(member)? {
+                    intersect_property(&mut properties, prop);
+                }
+            }
+            return Some(properties);
+        }
+
+        if let Some(properties) = object_literal_properties(type_str) {
+            return Some(properties);
+        }
+
+        let (type_name, type_arguments) =
+            split_type_reference(type_str).unwrap_or((type_str, Vec::new()));
+        if !self.is_named_type(type_name) {
+            match (type_name, type_arguments.as_slice()) {
+                ("Record", [key_type, value_type]) => {
+                    return Some(self.record_properties(key_type, value_type));
+                }
+                ("Partial" | "Required" | "Readonly", [inner_type]) => {
+                    let mut properties = self.resolve_type_properties(inner_type)?;
+                    for prop in &mut properties {
+                        match type_name {
+                            "Partial" => prop.optional = true,
+                            "Required" => prop.optional = false,
+                            _ => {}
+                        }
+                    }
+                    return Some(properties);
+                }
+                ("Pick" | "Omit", [inner_type, keys]) => {
+                    let keys = self.literal_keys(keys)?;
+                    let properties = self.resolve_type_properties(inner_type)?;
+                    let pick = type_name == "Pick";
+                    return Some(
+                        properties
+                            .into_iter()
+                            .filter(|prop| keys.contains(&prop.name) == pick)
+                            .collect(),
+                    );
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(interface) = self.interfaces.get(type_name) {
+            let substitutions =
+                type_parameter_substitutions(&interface.type_parameters, &type_arguments);
+            let properties = self
+                .resolve_properties(interface)
+                .into_iter()
+                .map(|mut prop| {
+                    prop.type_annotation =
+                        substitute_type_parameters(&prop.type_annotation, &substitutions);
+                    prop
+                })
+                .collect();
+            Some(properties)
+        } else {
+            self.resolve_type_properties(&self.expand_alias(type_str)?)
+        }
+    }
+
+    fn get_element_check(&self, element_type: &str, type_parameters: &[String]) -> String {
+        if self.is_simple_type(element_type) {
+            self.get_simple_type_check(element_type)
+        } else {
+            self.get_validator_reference(element_type, type_parameters)
+        }
+    }
+
+    /// Checks the length of a tuple and the type at each position, where
+    /// elements after a rest element are checked from the end of the array
+    fn get_tuple_validation(
+        &self,
+        elements: &[TupleElement],
+        value_expr: &str,
+        type_parameters: &[String],
+    ) -> String {
+        let rest_index = elements
+            .iter()
+            .position(|element| matches!(element, TupleElement::Rest(_)));
+        let (leading, trailing) = match rest_index {
+            Some(i) => (&elements[..i], &elements[i + 1..]),
+            None => (elements, &[][..]),
+        };
+        let required_count = elements
+            .iter()
+            .filter(|element| matches!(element, TupleElement::Required(_)))
+            .count();
+
+        let mut checks = vec![format!("Array.isArray({})", value_expr)];
+        if rest_index.is_some() {
+            checks.push(format!("{}.length >= {}", value_expr, required_count));
+        } else if required_count == elements.len() {
+            checks.push(format!("{}.length === {}", value_expr, required_count));
+        } else {
+            checks.push(format!(
+                "{}.length >= {} && {}.length <= {}",
+                value_expr,
+                required_count,
+                value_expr,
+                elements.len()
+            ));
+        }
+
+        for (i, element) in leading.iter().enumerate() {
+            let element_expr = format!("{}[{}]", value_expr, i);
+            checks.push(self.get_tuple_element_check(element, &element_expr, type_parameters));
+        }
+
+        for (i, element) in trailing.iter().enumerate() {
+            let element_expr = format!(
+                "{}[{}.length - {}]",
+                value_expr,
+                value_expr,
+                trailing.len() - i
+            );
+            checks.push(self.get_tuple_element_check(element, &element_expr, type_parameters));
+        }
+
+        if let Some(rest_type) = rest_index.and_then(|i| array_element_type(elements[i].type_str()))
+        {
+            let rest_expr = if trailing.is_empty() {
+                format!("{}.slice({})", value_expr, leading.len())
+            } else {
+                format!(
+                    "{}.slice({}, {}.length - {})",
+                    value_expr,
+                    leading.len(),
+                    value_expr,
+                    trailing.len()
+                )
+            };
+            checks.push(format!(
+                "{}.every({})",
+                rest_expr,
+                self.get_element_check(rest_type, type_parameters)
+            ));
+        }
+
+        format!("({})", checks.join(" && "))
+    }
+
+    fn get_tuple_element_check(
+        &self,
+        element: &TupleElement,
+        element_expr: &str,
+        type_parameters: &[String],
+    ) -> String {
+        let validation =
+            self.get_inline_validation(element.type_str(), element_expr, type_parameters);
+        match element {
+            TupleElement::Optional(_) => {
+                format!("({} === undefined || {})", element_expr, validation)
+            }
+            _ => validation,
+        }
+    }
+
+    /// Calls the validator of a named type, passing a validator for each of
+    /// its type parameters when it is generic
+    fn get_generic_validation(
+        &self,
+        type_name: &str,
+        type_arguments: &[&str],
+        value_expr: &str,
+        type_parameters: &[String],
+    ) -> String {
+        let substitutions =
+            type_parameter_substitutions(self.named_type_parameters(type_name), type_arguments);
+        if substitutions.is_empty() {
+            return format!("{}({})", self.type_validator_name(type_name), value_expr);
+        }
+
+        let argument_validators: Vec<String> = self
+            .named_type_parameters(type_name)
+            .iter()
+            .map(|param| self.get_validator_reference(&substitutions[&param.name], type_parameters))
+            .collect();
+        format!(
+            "{}({}, {})",
+            self.type_validator_name(type_name),
+            value_expr,
+            argument_validators.join(", ")
+        )
+    }
+
+    /// Returns an expression for a function validating the given type, which
+    /// can be passed to a generic validator or used as an array element check
+    fn get_validator_reference(&self, type_str: &str, type_parameters: &[String]) -> String {
+        let type_str = strip_parens(type_str);
+        if let Some(resolved) = self.resolve_qualified_name(type_str) {
+            return self.get_validator_reference(&resolved, type_parameters);
+        }
+        let is_type_parameter = type_parameters.iter().any(|param| param == type_str);
+        if is_type_parameter {
+            validator_name(type_str)
+        } else if self.is_named_type(type_str) && self.named_type_parameters(type_str).is_empty() {
+            self.type_validator_name(type_str)
+        } else {
+            self.collect_named_references(type_str, &mut self.predicate_references.borrow_mut());
+            format!(
+                "(v: unknown): v is {} => {}",
+                type_text(type_str),
+                self.get_inline_validation(type_str, "v", type_parameters)
+            )
+        }
+    }
+
+    /// Collects the declarations named by a type written into validators.ts,
+    /// such as the enum `Status` in `Status.Active | null` or the constant
+    /// `Roles` in `(typeof Roles)[number]`
+    fn collect_named_references(&self, type_str: &str, referenced_types: &mut HashSet<String>) {
+        for path in type_name_paths(type_str) {
+            let mut prefix = path;
+            loop {
+                if self.is_validator_target(prefix) || self.constants.contains_key(prefix) {
+                    referenced_types.insert(prefix.to_string());
+                    break;
+                }
+                match prefix.rsplit_once('.') {
+                    Some((shorter, _)) => prefix = shorter,
+                    None => break,
+                }
+            }
+        }
+    }
+
+    fn generate_enum_validation_negated(&self, enum_info: &EnumInfo, value_expr: &str) -> String {
+        // Generate validation that returns true when the value is NOT a valid enum member
+        let checks: Vec<String> = enum_member_checks(enum_info, value_expr)
+            .into_iter()
+            .map(|check| match check {
+                EnumCheck::Range(range) => format!("!({})", range),
+                EnumCheck::Equals(value) if value == "NaN" => {
+                    format!("!Number.isNaN({})", value_expr)
+                }
+                EnumCheck::Equals(value) => format!("{} !== {}", value_expr, value),
+                EnumCheck::Type(kind) => format!("typeof {} !== '{}'", value_expr, kind),
+            })
+            .collect();
+
+        if checks.is_empty() {
+            format!("{} === undefined", value_expr)
+        } else {
+            // Use AND because all conditions must be true for invalid value
+            // Join without wrapping in parentheses - they'll be added by the if statement
+            checks.join(" && ")
+        }
+    }
+
+    fn generate_enum_validation(&self, enum_info: &EnumInfo, value_expr: &str) -> String {
+        self.join_enum_checks(enum_member_checks(enum_info, value_expr), value_expr)
+    }
+
+    fn join_enum_checks(&self, enum_checks: Vec<EnumCheck>, value_expr: &str) -> String {
+        let checks: Vec<String> = enum_checks
+            .into_iter()
+            .map(|check| match check {
+                EnumCheck::Range(range) => range,
+                EnumCheck::Equals(value) if value == "NaN" => {
+                    format!("Number.isNaN({})", value_expr)
+                }
+                EnumCheck::Equals(value) => format!("{} === {}", value_expr, value),
+                EnumCheck::Type(kind) => format!("typeof {} === '{}'", value_expr, kind),
+            })
+            .collect();
+
+        if checks.is_empty() {
             // If all enum values are computed, fall back to basic check
             format!("{} !== undefined", value_expr)
         } else if checks.len() == 1 {
@@ -562,11 +1911,27 @@ impl ValidatorGenerator {
     fn is_simple_type(&self, type_str: &str) -> bool {
         matches!(
             type_str,
-            "string" | "number" | "boolean" | "any" | "void" | "null" | "undefined"
+            "string"
+                | "number"
+                | "boolean"
+                | "bigint"
+                | "symbol"
+                | "Function"
+                | "any"
+                | "unknown"
+                | "never"
+                | "void"
+                | "null"
+                | "undefined"
         )
     }
 
-    fn type_allows_undefined(&self, type_str: &str) -> bool {
+    fn type_allows_undefined(&self, type_str: &str, type_parameters: &[String]) -> bool {
+        let type_str = strip_parens(type_str);
+        if let Some(evaluated) = self.evaluate_type_operator(type_str) {
+            return self.type_allows_undefined(&evaluated, type_parameters);
+        }
+
         // Check if a type could be undefined or contain undefined
         if type_str == "any"
             || type_str == "undefined"
@@ -576,20 +1941,39 @@ impl ValidatorGenerator {
             return true;
         }
 
+        // A type parameter could be instantiated with a type that allows undefined
+        if type_parameters.iter().any(|param| param == type_str) {
+            return true;
+        }
+
+        // Aliases allow undefined when the aliased type does
+        if let Some(aliased_type) = self.expand_alias(type_str) {
+            return self.type_allows_undefined(&aliased_type, type_parameters);
+        }
+
         // Check union types
-        if type_str.contains(" | ") {
-            return type_str
-                .split(" | ")
-                .any(|t| self.type_allows_undefined(t.trim()));
+        let union_types = union_members(type_str);
+        if union_types.len() > 1 {
+            return union_types
+                .iter()
+                .any(|t| self.type_allows_undefined(t, type_parameters));
+        }
+
+        // An intersection only allows undefined when every member does
+        let intersection_types = intersection_members(type_str);
+        if intersection_types.len() > 1 {
+            return intersection_types
+                .iter()
+                .all(|t| self.type_allows_undefined(t, type_parameters));
         }
 
         // For array types, undefined is not allowed
-        if type_str.ends_with("[]") {
+        if array_element_type(type_str).is_some() {
             return false;
         }
 
         // For literal types, undefined is not allowed
-        if type_str.starts_with('\'') && type_str.ends_with('\'') {
+        if is_literal_type(type_str) {
             return false;
         }
 
@@ -597,28 +1981,48 @@ impl ValidatorGenerator {
         false
     }
 
-    fn get_negated_validation(&self, type_str: &str, value_expr: &str) -> String {
+    fn get_negated_validation(
+        &self,
+        type_str: &str,
+        value_expr: &str,
+        type_parameters: &[String],
+    ) -> String {
+        let type_str = strip_parens(type_str);
+        if let Some(evaluated) = self.evaluate_type_operator(type_str) {
+            return self.get_negated_validation(&evaluated, value_expr, type_parameters);
+        }
+        let union_types = union_members(type_str);
+
         match type_str {
             "string" => format!("typeof {} !== 'string'", value_expr),
             "number" => format!("typeof {} !== 'number'", value_expr),
             "boolean" => format!("typeof {} !== 'boolean'", value_expr),
+            "bigint" => format!("typeof {} !== 'bigint'", value_expr),
+            "symbol" => format!("typeof {} !== 'symbol'", value_expr),
+            "Function" => format!("typeof {} !== 'function'", value_expr),
+            "any" | "unknown" => "false".to_string(),
+            "never" => "true".to_string(),
             "null" => format!("{} !== null", value_expr),
             "undefined" => format!("{} !== undefined", value_expr),
-            _ if type_str.starts_with('\'') && type_str.ends_with('\'') => {
-                // Handle string literals
+            _ if is_literal_type(type_str) => {
+                // Handle literal types
                 format!("{} !== {}", value_expr, type_str)
             }
             _ => {
                 // Check if it's a union type
-                if type_str.contains(" | ") {
-                    let types: Vec<&str> = type_str.split(" | ").collect();
-                    let checks: Vec<String> = types
+                let object_types: Vec<&str> = union_types
+                    .iter()
+                    .copied()
+                    .filter(|t| self.resolve_type_properties(t).is_some())
+                    .collect();
+                if union_types.len() > 1 && self.find_discriminant(&object_types).is_none() {
+                    let checks: Vec<String> = union_types
                         .iter()
                         .map(|t| {
-                            if t.starts_with('\'') && t.ends_with('\'') {
+                            if is_literal_type(t) {
                                 format!("{} !== {}", value_expr, t)
                             } else {
-                                self.get_negated_validation(t, value_expr)
+                                self.get_negated_validation(t, value_expr, type_parameters)
                             }
                         })
                         .collect();
@@ -632,7 +2036,8 @@ impl ValidatorGenerator {
                     self.generate_enum_validation_negated(enum_info, value_expr)
                 } else {
                     // For complex types, fall back to negating the validation
-                    let validation = self.get_inline_validation(type_str, value_expr);
+                    let validation =
+                        self.get_inline_validation(type_str, value_expr, type_parameters);
                     // Check if validation already has outer parentheses
                     if validation.starts_with('(') && validation.ends_with(')') {
                         format!("!{}", validation)
@@ -649,7 +2054,11 @@ impl ValidatorGenerator {
             "string" => "(v: any) => typeof v === 'string'".to_string(),
             "number" => "(v: any) => typeof v === 'number'".to_string(),
             "boolean" => "(v: any) => typeof v === 'boolean'".to_string(),
-            "any" => "() => true".to_string(),
+            "bigint" => "(v: any) => typeof v === 'bigint'".to_string(),
+            "symbol" => "(v: any) => typeof v === 'symbol'".to_string(),
+            "Function" => "(v: any) => typeof v === 'function'".to_string(),
+            "any" | "unknown" => "() => true".to_string(),
+            "never" => "() => false".to_string(),
             "void" => "(v: any) => v === undefined".to_string(),
             "null" => "(v: any) => v === null".to_string(),
             "undefined" => "(v: any) => v === undefined".to_string(),
@@ -669,3 +2078,846 @@ impl ValidatorGenerator {
         Ok(())
     }
 }
+
+fn type_parameter_names(type_parameters: &[TypeParameterInfo]) -> Vec<String> {
+    type_parameters
+        .iter()
+        .map(|param| param.name.clone())
+        .collect()
+}
+
+/// Renders a type for use in generated code, where types that cannot be
+/// checked are written as `unknown`
+fn type_text(type_str: &str) -> String {
+    type_str.replace(UNSUPPORTED_TYPE, "unknown")
+}
+
+/// The possibly qualified names in a type, such as `Status.Active` and `Roles`
+/// in `Status.Active | (typeof Roles)[number]`, skipping string literals and
+/// property keys
+fn type_name_paths(type_str: &str) -> Vec<&str> {
+    let chars: Vec<(usize, char)> = type_str.char_indices().collect();
+    let is_name_char = |c: char| c.is_alphanumeric() || c == '_' || c == '$';
+    let mut paths = Vec::new();
+    let mut quote = None;
+    // The brace depths the placeholders of template literals were opened at
+    let mut placeholders = Vec::new();
+    let mut depth = 0;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i].1;
+        if let Some(quote_char) = quote {
+            if c == '\\' {
+                i += 1;
+            } else if c == quote_char {
+                quote = None;
+            } else if quote_char == '`'
+                && c == '$'
+                && chars.get(i + 1).map(|(_, c)| *c) == Some('{')
+            {
+                placeholders.push(depth);
+                depth += 1;
+                quote = None;
+                i += 1;
+            }
+            i += 1;
+            continue;
+        }
+
+        match c {
+            '\'' | '"' | '`' => quote = Some(c),
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if placeholders.last() == Some(&depth) {
+                    placeholders.pop();
+                    quote = Some('`');
+                }
+            }
+            _ if (c.is_alphabetic() || c == '_' || c == '$')
+                && (i == 0 || !(is_name_char(chars[i - 1].1) || chars[i - 1].1 == '.')) =>
+            {
+                let start = chars[i].0;
+                while i < chars.len()
+                    && (is_name_char(chars[i].1)
+                        || (chars[i].1 == '.'
+                            && chars.get(i + 1).is_some_and(|(_, c)| c.is_alphabetic())))
+                {
+                    i += 1;
+                }
+                let end = chars.get(i).map_or(type_str.len(), |(pos, _)| *pos);
+                if !type_str[end..].trim_start_matches('?').starts_with(':') {
+                    paths.push(&type_str[start..end]);
+                }
+                continue;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    paths
+}
+
+/// The name of the validator generated for a type, e.g. `validateApiOrder` for
+/// `Api.Order`
+fn validator_name(type_name: &str) -> String {
+    format!("validate{}", validator_type_name(type_name))
+}
+
+/// The type narrowed to by a validator, e.g. `Page<T>` for a generic interface
+fn guarded_type_name(type_name: &str, type_parameters: &[TypeParameterInfo]) -> String {
+    if type_parameters.is_empty() {
+        type_name.to_string()
+    } else {
+        format!(
+            "{}<{}>",
+            type_name,
+            type_parameter_names(type_parameters).join(", ")
+        )
+    }
+}
+
+/// Generic types get a higher-order validator which accepts a validator for
+/// each type parameter, e.g. `validatePage(value, validateUser)`
+fn validator_signature(
+    function_name: &str,
+    type_name: &str,
+    type_parameters: &[TypeParameterInfo],
+) -> String {
+    let guarded_type = guarded_type_name(type_name, type_parameters);
+    if type_parameters.is_empty() {
+        return format!(
+            "export function {}(value: unknown): value is {} {{\n",
+            function_name, guarded_type
+        );
+    }
+
+    let declarations: Vec<String> = type_parameters
+        .iter()
+        .map(|param| match &param.constraint {
+            Some(constraint) => format!("{} extends {}", param.name, type_text(constraint)),
+            None => param.name.clone(),
+        })
+        .collect();
+    let validators: Vec<String> = type_parameters
+        .iter()
+        .map(|param| {
+            format!(
+                "validate{}: (value: unknown) => value is {}",
+                param.name, param.name
+            )
+        })
+        .collect();
+
+    format!(
+        "export function {}<{}>(value: unknown, {}): value is {} {{\n",
+        function_name,
+        declarations.join(", "),
+        validators.join(", "),
+        guarded_type
+    )
+}
+
+/// Maps each type parameter to its type argument, falling back to the
+/// parameter's default when the argument is omitted
+fn type_parameter_substitutions(
+    type_parameters: &[TypeParameterInfo],
+    type_arguments: &[&str],
+) -> HashMap<String, String> {
+    type_parameters
+        .iter()
+        .enumerate()
+        .map(|(i, param)| {
+            let argument = match type_arguments.get(i) {
+                Some(arg) => arg.to_string(),
+                None => param.default.clone().unwrap_or_else(|| "any".to_string()),
+            };
+            (param.name.clone(), argument)
+        })
+        .collect()
+}
+
+/// Replaces references to type parameters within a type string
+fn substitute_type_parameters(type_str: &str, substitutions: &HashMap<String, String>) -> String {
+    if substitutions.is_empty() {
+        return type_str.to_string();
+    }
+
+    let mut result = String::new();
+    let mut identifier = String::new();
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+
+    let flush = |identifier: &mut String, result: &mut String| {
+        match substitutions.get(identifier.as_str()) {
+            // Qualified names such as `Namespace.T` do not refer to type parameters
+            Some(replacement) if !result.ends_with('.') => {
+                if union_members(replacement).len() > 1
+                    || split_top_level(replacement, " & ").len() > 1
+                {
+                    result.push_str(&format!("({})", replacement));
+                } else {
+                    result.push_str(replacement);
+                }
+            }
+            _ => result.push_str(identifier),
+        }
+        identifier.clear();
+    };
+
+    for c in type_str.chars() {
+        if let Some(q) = quote {
+            result.push(c);
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+        } else if c.is_alphanumeric() || c == '_' || c == '$' {
+            identifier.push(c);
+        } else {
+            flush(&mut identifier, &mut result);
+            if matches!(c, '\'' | '"' | '`') {
+                quote = Some(c);
+            }
+            result.push(c);
+        }
+    }
+    flush(&mut identifier, &mut result);
+
+    result
+}
+
+fn merge_property(properties: &mut Vec<PropertyInfo>, prop: PropertyInfo) {
+    if let Some(existing) = properties.iter_mut().find(|p| p.name == prop.name) {
+        *existing = prop;
+    } else {
+        properties.push(prop);
+    }
+}
+
+/// Returns the characters of a type string that are not within quotes, along
+/// with their byte offsets
+fn unquoted_chars(type_str: &str) -> Vec<(usize, char)> {
+    let mut chars = Vec::new();
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+
+    for (i, c) in type_str.char_indices() {
+        if let Some(q) = quote {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+        } else {
+            if matches!(c, '\'' | '"' | '`') {
+                quote = Some(c);
+            }
+            chars.push((i, c));
+        }
+    }
+
+    chars
+}
+
+/// Splits a type string on a separator, ignoring separators nested within
+/// brackets or quotes
+fn split_top_level<'a>(type_str: &'a str, separator: &str) -> Vec<&'a str> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+
+    for (i, c) in unquoted_chars(type_str) {
+        match c {
+            '(' | '[' | '{' | '<' => depth += 1,
+            ')' | ']' | '}' | '>' => depth -= 1,
+            _ if depth == 0 && i >= start && type_str[i..].starts_with(separator) => {
+                parts.push(type_str[start..i].trim());
+                start = i + separator.len();
+            }
+            _ => {}
+        }
+    }
+    parts.push(type_str[start..].trim());
+
+    parts
+}
+
+/// Splits a type string at the first separator outside of brackets or quotes
+fn split_top_level_once<'a>(type_str: &'a str, separator: &str) -> Option<(&'a str, &'a str)> {
+    let parts = split_top_level(type_str, separator);
+    if parts.len() < 2 {
+        return None;
+    }
+
+    let head = parts[0];
+    let offset = type_str.find(head).unwrap_or(0) + head.len();
+    let tail = type_str[offset..]
+        .trim_start()
+        .strip_prefix(separator.trim())?;
+    Some((head, tail.trim()))
+}
+
+/// Removes parentheses surrounding an entire type, e.g. `(A | B)` becomes `A | B`
+fn strip_parens(type_str: &str) -> &str {
+    let type_str = type_str.trim();
+    let Some(inner) = type_str
+        .strip_prefix('(')
+        .and_then(|inner| inner.strip_suffix(')'))
+    else {
+        return type_str;
+    };
+
+    // Make sure the opening parenthesis is not closed before the end, as in `(A) | (B)`
+    let mut depth = 0;
+    for (_, c) in unquoted_chars(inner) {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 0 => return type_str,
+            ')' => depth -= 1,
+            _ => {}
+        }
+    }
+
+    strip_parens(inner)
+}
+
+fn union_members(type_str: &str) -> Vec<&str> {
+    split_top_level(strip_parens(type_str), " | ")
+}
+
+/// Returns the members of an intersection, or a single member for other types
+fn intersection_members(type_str: &str) -> Vec<&str> {
+    let type_str = strip_parens(type_str);
+    if union_members(type_str).len() > 1 {
+        return vec![type_str];
+    }
+    split_top_level(type_str, " & ")
+        .into_iter()
+        .map(strip_parens)
+        .collect()
+}
+
+/// Combines a property of an intersection member with any property of the same
+/// name from another member, in which case the value must satisfy both types
+fn intersect_property(properties: &mut Vec<PropertyInfo>, prop: PropertyInfo) {
+    if let Some(existing) = properties.iter_mut().find(|p| p.name == prop.name) {
+        if existing.type_annotation != prop.type_annotation {
+            let wrap = |t: &str| {
+                if union_members(t).len() > 1 {
+                    format!("({})", t)
+                } else {
+                    t.to_string()
+                }
+            };
+            existing.type_annotation = format!(
+                "{} & {}",
+                wrap(&existing.type_annotation),
+                wrap(&prop.type_annotation)
+            );
+        }
+        existing.optional = existing.optional && prop.optional;
+    } else {
+        properties.push(prop);
+    }
+}
+
+fn is_literal_type(type_str: &str) -> bool {
+    // A union of string literals such as `'a' | 'b'` also starts and ends with quotes
+    let is_string_literal = type_str.starts_with('\'')
+        && type_str.ends_with('\'')
+        && union_members(type_str).len() == 1;
+    is_string_literal
+        || type_str == "true"
+        || type_str == "false"
+        || type_str.parse::<f64>().is_ok()
+}
+
+/// The primitive a type belongs to, where literal types belong to the
+/// primitive of their value
+fn primitive_kind(type_str: &str) -> Option<&'static str> {
+    match type_str {
+        "string" => Some("string"),
+        "number" => Some("number"),
+        "boolean" | "true" | "false" => Some("boolean"),
+        "bigint" => Some("bigint"),
+        "symbol" => Some("symbol"),
+        "null" => Some("null"),
+        "undefined" => Some("undefined"),
+        _ if type_str.starts_with('\'') && type_str.ends_with('\'') => Some("string"),
+        _ if template_literal_parts(type_str).is_some() => Some("string"),
+        _ if type_str.parse::<f64>().is_ok() => Some("number"),
+        _ => None,
+    }
+}
+
+/// A property telling the members of a union apart, with the literal values
+/// the property takes for each member
+struct Discriminant<'a> {
+    property: String,
+    cases: Vec<(Vec<String>, &'a str)>,
+}
+
+/// Splits an indexed access type such as `(typeof Roles)[number]` into the
+/// object type and the index type
+fn indexed_access(type_str: &str) -> Option<(&str, &str)> {
+    let inner = type_str.strip_suffix(']')?;
+    let mut depth = 0;
+    let mut open = None;
+    for (i, c) in unquoted_chars(inner).into_iter().rev() {
+        match c {
+            ']' | ')' | '}' | '>' => depth += 1,
+            '(' | '{' | '<' => depth -= 1,
+            '[' if depth == 0 => {
+                open = Some(i);
+                break;
+            }
+            '[' => depth -= 1,
+            _ => {}
+        }
+    }
+
+    let open = open?;
+    let (object_type, index_type) = (inner[..open].trim(), inner[open + 1..].trim());
+    if object_type.is_empty() || index_type.is_empty() {
+        return None;
+    }
+    Some((object_type, index_type))
+}
+
+/// Returns the key type of an index signature property such as `[key: string]`
+fn index_signature_key(name: &str) -> Option<&str> {
+    name.strip_prefix("[key: ")?.strip_suffix(']')
+}
+
+/// Accesses a property with dot notation when its name is an identifier and
+/// with brackets otherwise, as in `obj['content-type']`
+fn property_access(value_expr: &str, name: &str) -> String {
+    if is_identifier(name) {
+        format!("{}.{}", value_expr, name)
+    } else {
+        format!("{}[{}]", value_expr, quote_string(name))
+    }
+}
+
+/// A check accepting some of an enum's values
+#[derive(PartialEq)]
+enum EnumCheck {
+    /// A range of consecutive integers, such as `x >= 0 && x <= 3`
+    Range(String),
+    /// A single literal value
+    Equals(String),
+    /// Any value of a primitive type, used for computed members
+    Type(&'static str),
+}
+
+/// Collects the checks accepting every value of an enum. Computed members can
+/// hold any number at runtime, so they accept every number
+fn enum_member_checks(enum_info: &EnumInfo, value_expr: &str) -> Vec<EnumCheck> {
+    let has_computed = enum_info
+        .members
+        .iter()
+        .any(|member| matches!(member.value, EnumValue::Computed));
+    let mut integers: Vec<i64> = Vec::new();
+    let mut other_checks: Vec<EnumCheck> = Vec::new();
+
+    if has_computed {
+        other_checks.push(EnumCheck::Type("number"));
+    }
+
+    for member in &enum_info.members {
+        let check = match &member.value {
+            EnumValue::Number(_) | EnumValue::Computed if has_computed => continue,
+            EnumValue::Number(n) if n.fract() == 0.0 && n.abs() < 9007199254740992.0 => {
+                integers.push(*n as i64);
+                continue;
+            }
+            EnumValue::Number(n) => EnumCheck::Equals(format_number(*n)),
+            EnumValue::String(s) => EnumCheck::Equals(quote_string(s)),
+            EnumValue::Computed => continue,
+        };
+        if !other_checks.contains(&check) {
+            other_checks.push(check);
+        }
+    }
+
+    integers.sort();
+    integers.dedup();
+
+    // Consecutive integers are checked as a range when there are 3 or more
+    let mut checks = Vec::new();
+    let mut i = 0;
+    while i < integers.len() {
+        let start = integers[i];
+        let mut end = start;
+        while i + 1 < integers.len() && integers[i + 1] == end + 1 {
+            end = integers[i + 1];
+            i += 1;
+        }
+
+        if end - start >= 2 {
+            checks.push(EnumCheck::Range(format!(
+                "Number.isInteger({}) && {} >= {} && {} <= {}",
+                value_expr, value_expr, start, value_expr, end
+            )));
+        } else {
+            for value in start..=end {
+                checks.push(EnumCheck::Equals(value.to_string()));
+            }
+        }
+        i += 1;
+    }
+
+    checks.extend(other_checks);
+    checks
+}
+
+/// Splits a template literal type such as `` `user_${string}` `` into its text
+/// parts and the types interpolated between them
+fn template_literal_parts(type_str: &str) -> Option<(Vec<String>, Vec<&str>)> {
+    let type_str = strip_parens(type_str);
+    if union_members(type_str).len() > 1 || split_top_level(type_str, " & ").len() > 1 {
+        return None;
+    }
+
+    let inner = type_str.strip_prefix('`')?.strip_suffix('`')?;
+    let mut quasis = vec![String::new()];
+    let mut types = Vec::new();
+    let mut chars = inner.char_indices();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => {
+                let (_, escaped) = chars.next()?;
+                quasis.last_mut()?.push(escaped);
+            }
+            '$' if inner[i + 1..].starts_with('{') => {
+                let start = i + 2;
+                let mut depth = 1;
+                let end = unquoted_chars(&inner[start..])
+                    .into_iter()
+                    .find_map(|(j, c)| {
+                        match c {
+                            '{' => depth += 1,
+                            '}' => depth -= 1,
+                            _ => {}
+                        }
+                        (depth == 0).then_some(start + j)
+                    })?;
+                types.push(inner[start..end].trim());
+                quasis.push(String::new());
+                // Skip past the closing brace of the interpolated type
+                for (j, _) in chars.by_ref() {
+                    if j == end {
+                        break;
+                    }
+                }
+            }
+            _ => quasis.last_mut()?.push(c),
+        }
+    }
+
+    Some((quasis, types))
+}
+
+/// Renders the parts of a template literal type back into a type string
+fn template_literal_string(quasis: &[String], types: &[&str]) -> String {
+    let mut template = String::from("`");
+    template.push_str(&quasis[0]);
+    for (type_str, quasi) in types.iter().zip(&quasis[1..]) {
+        template.push_str(&format!("${{{}}}{}", type_str, quasi));
+    }
+    template.push('`');
+    template
+}
+
+/// Escapes text so that it matches literally within a regular expression literal
+fn regex_escape(text: &str) -> String {
+    let mut escaped = String::new();
+    for c in text.chars() {
+        match c {
+            '\\' | '^' | '$' | '.' | '|' | '?' | '*' | '+' | '(' | ')' | '[' | ']' | '{' | '}'
+            | '/' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Parses the properties of an object literal type such as
+/// `{ street: string; zip?: string }`
+fn object_literal_properties(type_str: &str) -> Option<Vec<PropertyInfo>> {
+    let type_str = strip_parens(type_str);
+    if union_members(type_str).len() > 1 || split_top_level(type_str, " & ").len() > 1 {
+        return None;
+    }
+
+    let inner = type_str.strip_prefix('{')?.strip_suffix('}')?.trim();
+    if inner.is_empty() {
+        return Some(Vec::new());
+    }
+
+    let properties = split_top_level(inner, "; ")
+        .into_iter()
+        .filter_map(|member| {
+            let (key, type_annotation) = split_top_level_once(member, ": ")?;
+            let (name, optional) = match key.strip_suffix('?') {
+                Some(name) => (name, true),
+                None => (key, false),
+            };
+            // Keys that are not identifiers are quoted, as in `'content-type'`
+            let name = unquote_string(name).unwrap_or_else(|| name.to_string());
+            Some(PropertyInfo {
+                name,
+                type_annotation: type_annotation.to_string(),
+                optional,
+            })
+        })
+        .collect();
+
+    Some(properties)
+}
+
+/// Returns the element type when the whole type is an array such as `User[]`
+/// or `(A | B)[]`
+fn array_element_type(type_str: &str) -> Option<&str> {
+    let type_str = strip_parens(type_str);
+    if union_members(type_str).len() > 1 || split_top_level(type_str, " & ").len() > 1 {
+        return None;
+    }
+    type_str
+        .strip_suffix("[]")
+        .map(strip_parens)
+        .filter(|element_type| !element_type.is_empty())
+}
+
+enum TupleElement<'a> {
+    Required(&'a str),
+    Optional(&'a str),
+    Rest(&'a str),
+}
+
+impl<'a> TupleElement<'a> {
+    fn type_str(&self) -> &'a str {
+        match self {
+            TupleElement::Required(type_str)
+            | TupleElement::Optional(type_str)
+            | TupleElement::Rest(type_str) => type_str,
+        }
+    }
+}
+
+/// Returns the elements when the whole type is a tuple such as `[string, number?]`
+fn tuple_elements(type_str: &str) -> Option<Vec<TupleElement<'_>>> {
+    let type_str = strip_parens(type_str);
+    let is_compound =
+        union_members(type_str).len() > 1 || split_top_level(type_str, " & ").len() > 1;
+    if is_compound || array_element_type(type_str).is_some() {
+        return None;
+    }
+
+    let inner = type_str.strip_prefix('[')?.strip_suffix(']')?;
+    if inner.trim().is_empty() {
+        return Some(Vec::new());
+    }
+
+    let elements = split_top_level(inner, ", ")
+        .into_iter()
+        .map(|element| {
+            if let Some(rest_type) = element.strip_prefix("...") {
+                TupleElement::Rest(strip_parens(rest_type))
+            } else if let Some(optional_type) = element.strip_suffix('?') {
+                TupleElement::Optional(strip_parens(optional_type))
+            } else {
+                TupleElement::Required(strip_parens(element))
+            }
+        })
+        .collect();
+
+    Some(elements)
+}
+
+/// Splits a generic type reference such as `Page<User>` into its name and
+/// type arguments
+fn split_type_reference(type_str: &str) -> Option<(&str, Vec<&str>)> {
+    let type_str = strip_parens(type_str);
+    let open = type_str.find('<')?;
+    let type_name = &type_str[..open];
+    let arguments = type_str[open + 1..].strip_suffix('>')?;
+
+    let is_identifier = type_name
+        .chars()
+        .all(|c| c.is_alphanumeric() || c == '_' || c == '$' || c == '.');
+    let is_compound =
+        union_members(type_str).len() > 1 || split_top_level(type_str, " & ").len() > 1;
+    if type_name.is_empty() || !is_identifier || is_compound {
+        return None;
+    }
+
+    Some((type_name, split_top_level(arguments, ", ")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::tests::{module_dir, parse_modules};
+
+    /// Generates the validators requested by modules, written next to them
+    fn generate(name: &str, modules: &[(&str, &str)]) -> String {
+        let dir = module_dir(name);
+        let parser = parse_modules(&dir, modules);
+        let mut generator = ValidatorGenerator::new(
+            parser.interfaces,
+            parser.type_aliases,
+            parser.constants,
+            parser.enums,
+            parser.export_names,
+            Vec::new(),
+            false,
+        );
+        generator.generate_validators(
+            &parser.validator_functions,
+            &dir.join("validators.ts").to_string_lossy(),
+        )
+    }
+
+    /// The generated function with the given name
+    fn validator<'a>(output: &'a str, name: &str) -> &'a str {
+        let start = output
+            .find(&format!("export function {}", name))
+            .unwrap_or_else(|| panic!("{} was not generated", name));
+        let end = output[start..].find("\n}").unwrap() + start + 2;
+        &output[start..end]
+    }
+
+    #[test]
+    fn evaluates_typeof_with_the_constant_of_the_referencing_module() {
+        let output = generate(
+            "typeof-module-constants",
+            &[
+                (
+                    "admin.ts",
+                    "export const Roles = ['admin', 'user'] as const\n\
+                     export interface Admin { role: (typeof Roles)[number] }\n",
+                ),
+                (
+                    "bot.ts",
+                    "export const Roles = ['x', 'y'] as const\n\
+                     export interface Bot { role: (typeof Roles)[number] }\n",
+                ),
+                (
+                    "main.ts",
+                    "import { Admin } from './admin'\n\
+                     import { Bot } from './bot'\n\
+                     validateAdmin(1)\n\
+                     validateBot(1)\n",
+                ),
+            ],
+        );
+
+        assert!(validator(&output, "validateAdmin")
+            .contains("obj.role !== 'admin' && obj.role !== 'user'"));
+        assert!(validator(&output, "validateBot").contains("obj.role !== 'x' && obj.role !== 'y'"));
+    }
+
+    #[test]
+    fn imports_enums_and_constants_named_by_inline_predicates() {
+        let output = generate(
+            "predicate-imports",
+            &[
+                (
+                    "page.ts",
+                    "export enum Status { Active = 'active', Closed = 'closed' }\n\
+                     export const Roles = ['admin', 'user'] as const\n\
+                     export interface Page<T> { items: T[] }\n\
+                     export interface Report {\n\
+                       statuses: Page<Status | null>\n\
+                       roles: Page<(typeof Roles)[number]>\n\
+                     }\n",
+                ),
+                (
+                    "main.ts",
+                    "import { Report } from './page'\n\
+                     validateReport(1)\n",
+                ),
+            ],
+        );
+
+        assert!(output.contains("import type { Page, Report, Roles, Status } from './page';"));
+        let report = validator(&output, "validateReport");
+        assert!(report.contains("(v: unknown): v is Status | null =>"));
+        assert!(report.contains("(v: unknown): v is (typeof Roles)[number] =>"));
+    }
+
+    #[test]
+    fn imports_enums_named_by_type_parameter_constraints() {
+        let output = generate(
+            "constraint-imports",
+            &[
+                (
+                    "filter.ts",
+                    "export enum Status { Active = 'active' }\n\
+                     export interface Filter<T extends Status> { value: T }\n",
+                ),
+                (
+                    "main.ts",
+                    "import { Filter } from './filter'\n\
+                     validateFilter(1)\n",
+                ),
+            ],
+        );
+
+        assert!(output.contains("import type { Filter, Status } from './filter';"));
+    }
+
+    #[test]
+    fn finds_names_outside_string_literals_and_property_keys() {
+        assert_eq!(
+            type_name_paths("Status.Active | { 'Roles': Item; key?: Role } | `${Prefix}-Tail`"),
+            vec!["Status.Active", "Item", "Role", "Prefix"]
+        );
+    }
+
+    #[test]
+    fn requires_numeric_and_enum_record_keys() {
+        let output = generate(
+            "record-keys",
+            &[(
+                "tables.ts",
+                "export enum Color { Red = 'red', Blue = 'blue' }\n\
+                 export enum Level { Low, High }\n\
+                 export interface Tables {\n\
+                   podium: Record<1 | 2, string>\n\
+                   colors: Record<Color, number>\n\
+                   levels: Record<Level, boolean>\n\
+                 }\n\
+                 validateTables(1)\n",
+            )],
+        );
+
+        let tables = validator(&output, "validateTables");
+        assert!(tables.contains(
+            "'1' in obj.podium && typeof obj.podium['1'] === 'string' && \
+             '2' in obj.podium && typeof obj.podium['2'] === 'string'"
+        ));
+        assert!(tables.contains(
+            "'red' in obj.colors && typeof obj.colors.red === 'number' && \
+             'blue' in obj.colors && typeof obj.colors.blue === 'number'"
+        ));
+        assert!(tables.contains("'0' in obj.levels && typeof obj.levels['0'] === 'boolean'"));
+        assert!(!tables.contains("Object.values") && !tables.contains("Object.entries"));
+    }
+}
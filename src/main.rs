@@ -56,6 +56,12 @@ fn scan_and_generate(config: &Config) -> Result<(), Box<dyn std::error::Error>>
     if !config.conditions.is_empty() {
         println!("  Export conditions: {:?}", config.conditions);
     }
+    if !config.nominal_classes.is_empty() {
+        println!("  Nominal classes: {:?}", config.nominal_classes);
+    }
+    if !config.type_files.is_empty() {
+        println!("  Type files: {:?}", config.type_files);
+    }
 
     let pattern_regex = config.get_pattern_regex();
     let mut parser = TypeScriptParser::new(
@@ -78,6 +84,13 @@ fn scan_and_generate(config: &Config) -> Result<(), Box<dyn std::error::Error>>
     for path in &source_paths {
         parser.mark_as_source_file(path);
     }
+    parser.mark_as_generated_file(Path::new(&config.validator_file));
+
+    // Source files augmented by type files are loaded with them, so they must
+    // be marked first for their validator calls to be collected
+    for path in load_type_files(config, &mut parser)? {
+        println!("  Loaded declarations: {:?}", path);
+    }
 
     // Now parse all source files
     for path in source_paths {
@@ -88,6 +101,7 @@ fn scan_and_generate(config: &Config) -> Result<(), Box<dyn std::error::Error>>
 
     println!("\nFound {} TypeScript files", file_count);
     println!("Found {} interfaces", parser.interfaces.len());
+    println!("Found {} type aliases", parser.type_aliases.len());
     println!("Found {} enums", parser.enums.len());
     println!(
         "Found {} validator function calls",
@@ -105,7 +119,11 @@ fn scan_and_generate(config: &Config) -> Result<(), Box<dyn std::error::Error>>
         // Check for missing interfaces
         let missing_interfaces: Vec<_> = requested_interfaces
             .iter()
-            .filter(|name| !parser.interfaces.contains_key(name.as_str()))
+            .filter(|name| {
+                !parser.interfaces.contains_key(name.as_str())
+                    && !parser.type_aliases.contains_key(name.as_str())
+                    && !parser.enums.contains_key(name.as_str())
+            })
             .collect();
 
         if !missing_interfaces.is_empty() {
@@ -143,11 +161,26 @@ fn scan_and_generate(config: &Config) -> Result<(), Box<dyn std::error::Error>>
                     interface_name,
                     interface.properties.len()
                 );
+            } else if let Some(alias) = parser.type_aliases.get(*interface_name) {
+                println!("\n  {} (= {})", interface_name, alias.type_annotation);
+            } else if let Some(enum_info) = parser.enums.get(*interface_name) {
+                println!(
+                    "\n  {} (enum with {} members)",
+                    interface_name,
+                    enum_info.members.len()
+                );
             }
         }
 
-        let generator =
-            ValidatorGenerator::new(parser.interfaces, parser.enums, config.use_js_extensions);
+        let mut generator = ValidatorGenerator::new(
+            parser.interfaces,
+            parser.type_aliases,
+            parser.constants,
+            parser.enums,
+            parser.export_names,
+            config.nominal_classes.clone(),
+            config.use_js_extensions,
+        );
         let output =
             generator.generate_validators(&parser.validator_functions, &config.validator_file);
 
@@ -168,6 +201,28 @@ fn scan_and_generate(config: &Config) -> Result<(), Box<dyn std::error::Error>>
     Ok(())
 }
 
+/// Parses the ambient declaration files listed in `typeFiles` or tsconfig.json,
+/// so that types declared in `declare module 'pkg' { ... }` or globally can be
+/// referenced by the source files
+fn load_type_files(
+    config: &Config,
+    parser: &mut TypeScriptParser,
+) -> Result<Vec<PathBuf>, Box<dyn std::error::Error>> {
+    let mut type_paths = Vec::new();
+    for pattern in config.get_type_file_patterns() {
+        type_paths.extend(
+            glob(&pattern.pattern)?
+                .filter_map(|entry| entry.ok())
+                .filter(|path| path.is_file() && !pattern.excludes(path)),
+        );
+    }
+
+    for path in &type_paths {
+        parser.parse_file(path)?;
+    }
+    Ok(type_paths)
+}
+
 fn add_interface_validator(
     config: &Config,
     interface_name: &str,
@@ -203,30 +258,42 @@ fn add_interface_validator(
     for path in &source_paths {
         parser.mark_as_source_file(path);
     }
+    parser.mark_as_generated_file(Path::new(&config.validator_file));
 
+    load_type_files(config, &mut parser)?;
     for path in source_paths {
         parser.parse_file(&path)?;
     }
 
     // Check if the interface was found
-    if !parser.interfaces.contains_key(interface_name) {
+    if !parser.interfaces.contains_key(interface_name)
+        && !parser.type_aliases.contains_key(interface_name)
+        && !parser.enums.contains_key(interface_name)
+    {
         eprintln!("\nError: Interface '{}' not found.", interface_name);
         eprintln!("\nAvailable interfaces:");
-        let mut interface_names: Vec<_> = parser.interfaces.keys().collect();
+        let mut interface_names: Vec<_> = parser
+            .interfaces
+            .keys()
+            .chain(parser.type_aliases.keys())
+            .chain(parser.enums.keys())
+            .collect();
         interface_names.sort();
         for name in interface_names.iter().take(20) {
             eprintln!("  - {}", name);
         }
-        if parser.interfaces.len() > 20 {
-            eprintln!("  ... and {} more", parser.interfaces.len() - 20);
+        if interface_names.len() > 20 {
+            eprintln!("  ... and {} more", interface_names.len() - 20);
         }
         std::process::exit(1);
     }
 
     println!("\nFound interface '{}'", interface_name);
 
-    // Generate the validator function name
-    let validator_name = config.validator_pattern.replace("%(type)", interface_name);
+    // Generate the validator function name, as `validateApiOrder` for `Api.Order`
+    let validator_name = config
+        .validator_pattern
+        .replace("%(type)", &parser::validator_type_name(interface_name));
 
     // Read existing validators file if it exists
     let output_path = Path::new(&config.validator_file);
@@ -236,8 +303,17 @@ fn add_interface_validator(
         String::new()
     };
 
+    // Parse existing validators to maintain them
+    let mut existing_validators: Vec<_> = existing_content
+        .lines()
+        .filter_map(parse_existing_validator)
+        .collect();
+
     // Check if validator already exists
-    if existing_content.contains(&format!("function {}", validator_name)) {
+    if existing_validators
+        .iter()
+        .any(|validator| validator.name == validator_name)
+    {
         println!(
             "\nValidator '{}' already exists in {}",
             validator_name, config.validator_file
@@ -245,27 +321,6 @@ fn add_interface_validator(
         return Ok(());
     }
 
-    // Parse existing validators to maintain them
-    let mut existing_validators = Vec::new();
-    if !existing_content.is_empty() {
-        // Extract existing validator functions from the file
-        for line in existing_content.lines() {
-            if line.starts_with("export function validate") {
-                if let Some(name_start) = line.find("validate") {
-                    if let Some(paren_pos) = line[name_start..].find('(') {
-                        let func_name = &line[name_start..name_start + paren_pos];
-                        if let Some(interface_name_match) = func_name.strip_prefix("validate") {
-                            existing_validators.push(parser::ValidatorFunction {
-                                name: func_name.to_string(),
-                                interface_name: interface_name_match.to_string(),
-                            });
-                        }
-                    }
-                }
-            }
-        }
-    }
-
     // Add the new validator
     let new_validator = parser::ValidatorFunction {
         name: validator_name.clone(),
@@ -277,8 +332,15 @@ fn add_interface_validator(
     existing_validators.sort_by(|a, b| a.name.cmp(&b.name));
 
     // Generate the updated validators file
-    let generator =
-        ValidatorGenerator::new(parser.interfaces, parser.enums, config.use_js_extensions);
+    let mut generator = ValidatorGenerator::new(
+        parser.interfaces,
+        parser.type_aliases,
+        parser.constants,
+        parser.enums,
+        parser.export_names,
+        config.nominal_classes.clone(),
+        config.use_js_extensions,
+    );
     let output = generator.generate_validators(&existing_validators, &config.validator_file);
 
     // Write the updated file
@@ -292,3 +354,24 @@ fn add_interface_validator(
 
     Ok(())
 }
+
+/// Reads a validator from its declaration in a generated validators file. The
+/// type is read from the type guard, as in `value is Contact2`, since the name
+/// of the validator does not identify types declared in namespaces or sharing
+/// their name with types in other modules
+fn parse_existing_validator(line: &str) -> Option<parser::ValidatorFunction> {
+    let declaration = line.strip_prefix("export function validate")?;
+    let name_end = declaration.find(['(', '<'])?;
+    let (_, guarded_type) = declaration.rsplit_once("): value is ")?;
+    let guarded_type = guarded_type.trim_end().strip_suffix('{')?.trim_end();
+
+    // Generic validators guard the type applied to their type parameters
+    let type_name = guarded_type
+        .split_once('<')
+        .map_or(guarded_type, |(type_name, _)| type_name);
+
+    Some(parser::ValidatorFunction {
+        name: format!("validate{}", &declaration[..name_end]),
+        interface_name: type_name.to_string(),
+    })
+}
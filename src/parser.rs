@@ -1,5 +1,6 @@
 use oxc_allocator::Allocator;
 use oxc_ast::ast::*;
+use oxc_ast_visit::{walk, Visit};
 use oxc_parser::Parser;
 use oxc_resolver::{ResolveOptions, Resolver};
 use oxc_span::SourceType;
@@ -8,41 +9,147 @@ use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
 
+/// Recorded for types that cannot be checked, such as conditional and mapped
+/// types. Unlike `unknown`, which accepts any value, properties of these types
+/// are still required to be present
+pub const UNSUPPORTED_TYPE: &str = "__unsupported__";
+
 pub struct InterfaceInfo {
     pub name: String,
+    /// The name the type is declared with. Types are recorded under `name`,
+    /// which has a numeric suffix when another module declares a type with
+    /// the same name
+    pub declared_name: String,
+    pub type_parameters: Vec<TypeParameterInfo>,
     pub properties: Vec<PropertyInfo>,
+    pub extends: Vec<String>,
+    /// Whether the interface has call or construct signatures
+    pub callable: bool,
+    /// Whether the type is a class, which can also be checked with `instanceof`
+    pub is_class: bool,
     pub file_path: String,
 }
 
+pub struct TypeParameterInfo {
+    pub name: String,
+    pub constraint: Option<String>,
+    pub default: Option<String>,
+}
+
+#[derive(Clone)]
 pub struct PropertyInfo {
     pub name: String,
     pub type_annotation: String,
     pub optional: bool,
 }
 
+pub struct TypeAliasInfo {
+    pub name: String,
+    pub declared_name: String,
+    pub type_parameters: Vec<TypeParameterInfo>,
+    pub type_annotation: String,
+    pub file_path: String,
+}
+
+/// The type of a `const` declaration, used to evaluate types such as
+/// `typeof Roles[number]`. Constants are keyed by module like types, and share
+/// the key of a type declared with the same name in the same module
+pub struct ConstantInfo {
+    pub declared_name: String,
+    pub type_annotation: String,
+    pub file_path: String,
+}
+
 pub struct ValidatorFunction {
     pub name: String,
     pub interface_name: String,
 }
 
 pub struct EnumInfo {
+    pub declared_name: String,
     pub members: Vec<EnumMember>,
+    pub file_path: String,
 }
 
 pub struct EnumMember {
+    pub name: String,
     pub value: EnumValue,
 }
 
+#[derive(Clone)]
 pub enum EnumValue {
     String(String),
     Number(f64),
     Computed,
 }
 
+/// An enum member initialized from a member of an enum that has not been
+/// parsed yet
+struct EnumReference {
+    enum_key: String,
+    member_index: usize,
+    target_module: PathBuf,
+    target_enum: String,
+    target_member: String,
+}
+
+/// What a name used in a type refers to in the file being parsed
+enum TypeBinding {
+    /// A type declared in a module, either the file itself or the module it
+    /// is imported from. `name` is the name the module exports the type as,
+    /// which is `default` for default imports
+    Declaration { module: PathBuf, name: String },
+    /// A namespace import such as `import * as Api from './api'`
+    Namespace { module: PathBuf },
+}
+
+/// A type re-exported from another module
+enum ModuleExport {
+    /// `export * from './user'`
+    All { module: PathBuf },
+    /// `export { Customer as User } from './user'`, where `local` is the name
+    /// of the type in `module`
+    Named {
+        name: String,
+        module: PathBuf,
+        local: String,
+    },
+    /// `export = Api`, which exports the members of the namespace `Api`
+    Equals { local: String },
+}
+
 pub struct TypeScriptParser {
     pub interfaces: HashMap<String, InterfaceInfo>,
+    pub type_aliases: HashMap<String, TypeAliasInfo>,
+    pub constants: HashMap<String, ConstantInfo>,
     pub enums: HashMap<String, EnumInfo>,
+    /// The names types are exported as when their module renames them, such
+    /// as `Address` for `export { MailingAddress as Address }`, `default`, or
+    /// `=` for `export = Api`
+    pub export_names: HashMap<String, String>,
     pub validator_functions: Vec<ValidatorFunction>,
+    pending_enum_references: Vec<EnumReference>,
+    /// Interfaces only declared in module augmentations so far
+    augmentation_only_interfaces: HashSet<String>,
+    in_augmentation: bool,
+    /// The module declarations are added to, which is the file being parsed
+    /// except in module augmentations
+    current_module: PathBuf,
+    /// The qualified name of the namespace being processed, such as `Api.V1`
+    current_namespace: Option<String>,
+    /// The modules imported by the file being parsed, by import path
+    module_paths: HashMap<String, PathBuf>,
+    /// The types that names refer to in the file being parsed
+    scope: HashMap<String, TypeBinding>,
+    /// Keys of the types declared in each module. Types are keyed by their
+    /// name unless a type with the same name was declared in another module
+    declaration_keys: HashMap<(PathBuf, String), String>,
+    type_keys: HashSet<String>,
+    module_exports: HashMap<PathBuf, Vec<ModuleExport>>,
+    /// Types referenced by declarations, which are resolved to their keys once
+    /// the modules declaring them have been parsed
+    type_references: Vec<(PathBuf, String)>,
+    parse_depth: usize,
     validator_pattern: Regex,
     parsed_files: HashSet<PathBuf>,
     source_files: HashSet<PathBuf>,
@@ -59,79 +166,97 @@ impl TypeScriptParser {
         exclude_packages: Vec<String>,
         export_conditions: Vec<String>,
     ) -> Self {
-        let mut resolve_options = ResolveOptions::default();
-
-        // Configure for TypeScript resolution
-        resolve_options.extensions = vec![
-            ".ts".to_string(),
-            ".tsx".to_string(),
-            ".d.ts".to_string(),
-            ".js".to_string(),
-            ".jsx".to_string(),
-            ".json".to_string(),
-        ];
-
-        // Enable TypeScript mode for proper .js -> .ts resolution
-        resolve_options.extension_alias = vec![
-            (
-                ".js".to_string(),
-                vec![".ts".to_string(), ".tsx".to_string(), ".js".to_string()],
-            ),
-            (
-                ".jsx".to_string(),
-                vec![".tsx".to_string(), ".jsx".to_string()],
-            ),
-            (
-                ".mjs".to_string(),
-                vec![".mts".to_string(), ".mjs".to_string()],
-            ),
-            (
-                ".cjs".to_string(),
-                vec![".cts".to_string(), ".cjs".to_string()],
-            ),
-        ];
-
-        // Enable exports field support
-        resolve_options.exports_fields = vec![vec!["exports".to_string()]];
-
-        // Set main fields for module resolution
-        resolve_options.main_fields = vec![
-            "types".to_string(),
-            "typings".to_string(),
-            "module".to_string(),
-            "main".to_string(),
-        ];
-
-        // Enable resolving index files
-        resolve_options.main_files = vec!["index".to_string()];
-
-        // Prefer relative imports to resolve as-is
-        resolve_options.prefer_relative = true;
-
         // Set export conditions (e.g., "dev", "production", "import", "require")
-
-        if !export_conditions.is_empty() {
+        let condition_names = if !export_conditions.is_empty() {
             // Add custom conditions first, then default ones
             let mut conditions = export_conditions;
             conditions.push("types".to_string());
             conditions.push("import".to_string());
             conditions.push("node".to_string());
             conditions.push("default".to_string());
-            resolve_options.condition_names = conditions;
+            conditions
         } else {
             // Default conditions for TypeScript/Node.js
-            resolve_options.condition_names = vec![
+            vec![
                 "types".to_string(),
                 "import".to_string(),
                 "node".to_string(),
                 "default".to_string(),
-            ];
-        }
+            ]
+        };
+
+        let resolve_options = ResolveOptions {
+            // Configure for TypeScript resolution
+            extensions: vec![
+                ".ts".to_string(),
+                ".tsx".to_string(),
+                ".d.ts".to_string(),
+                ".js".to_string(),
+                ".jsx".to_string(),
+                ".json".to_string(),
+            ],
+
+            // Enable TypeScript mode for proper .js -> .ts resolution
+            extension_alias: vec![
+                (
+                    ".js".to_string(),
+                    vec![".ts".to_string(), ".tsx".to_string(), ".js".to_string()],
+                ),
+                (
+                    ".jsx".to_string(),
+                    vec![".tsx".to_string(), ".jsx".to_string()],
+                ),
+                (
+                    ".mjs".to_string(),
+                    vec![".mts".to_string(), ".mjs".to_string()],
+                ),
+                (
+                    ".cjs".to_string(),
+                    vec![".cts".to_string(), ".cjs".to_string()],
+                ),
+            ],
+
+            // Enable exports field support
+            exports_fields: vec![vec!["exports".to_string()]],
+
+            // Set main fields for module resolution
+            main_fields: vec![
+                "types".to_string(),
+                "typings".to_string(),
+                "module".to_string(),
+                "main".to_string(),
+            ],
+
+            // Enable resolving index files
+            main_files: vec!["index".to_string()],
+
+            // Prefer relative imports to resolve as-is
+            prefer_relative: true,
+
+            condition_names,
+
+            ..ResolveOptions::default()
+        };
 
         Self {
             interfaces: HashMap::new(),
+            type_aliases: HashMap::new(),
+            constants: HashMap::new(),
             enums: HashMap::new(),
+            export_names: HashMap::new(),
             validator_functions: Vec::new(),
+            pending_enum_references: Vec::new(),
+            augmentation_only_interfaces: HashSet::new(),
+            in_augmentation: false,
+            current_module: PathBuf::new(),
+            current_namespace: None,
+            module_paths: HashMap::new(),
+            scope: HashMap::new(),
+            declaration_keys: HashMap::new(),
+            type_keys: HashSet::new(),
+            module_exports: HashMap::new(),
+            type_references: Vec::new(),
+            parse_depth: 0,
             validator_pattern: Regex::new(pattern).unwrap(),
             parsed_files: HashSet::new(),
             source_files: HashSet::new(),
@@ -148,7 +273,31 @@ impl TypeScriptParser {
         }
     }
 
+    /// Excludes the generated validators file from parsing. It imports every
+    /// validated type, which would otherwise change the order types are
+    /// declared in and so the keys of types sharing a name between runs
+    pub fn mark_as_generated_file(&mut self, path: &Path) {
+        if let Ok(canonical_path) = path.canonicalize() {
+            self.parsed_files.insert(canonical_path);
+        }
+    }
+
     pub fn parse_file(&mut self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        self.parse_depth += 1;
+        let result = self.parse_module(path);
+        self.parse_depth -= 1;
+
+        // Every module imported by the file has been parsed once the outermost
+        // call returns, so the types it references can be resolved
+        if self.parse_depth == 0 {
+            self.resolve_type_references();
+            self.collect_export_names();
+        }
+
+        result
+    }
+
+    fn parse_module(&mut self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
         // Get canonical path to avoid parsing the same file twice
         let canonical_path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
 
@@ -164,7 +313,7 @@ impl TypeScriptParser {
 
         let source_text = fs::read_to_string(path)?;
         let allocator = Allocator::default();
-        let source_type = SourceType::from_path(path).unwrap_or(SourceType::default());
+        let source_type = SourceType::from_path(path).unwrap_or_default();
 
         let parser = Parser::new(&allocator, &source_text, source_type);
         let result = parser.parse();
@@ -174,7 +323,19 @@ impl TypeScriptParser {
             return Ok(());
         }
 
-        let file_path_str = path.to_string_lossy().to_string();
+        // Declaration files without imports or exports declare global types,
+        // which are not imported from the file
+        let is_global = path.to_string_lossy().ends_with(".d.ts")
+            && !result
+                .program
+                .body
+                .iter()
+                .any(|stmt| stmt.as_module_declaration().is_some());
+        let file_path_str = if is_global {
+            String::new()
+        } else {
+            path.to_string_lossy().to_string()
+        };
 
         // Collect imports before processing the program
         let imports = self.collect_imports(&result.program);
@@ -183,11 +344,42 @@ impl TypeScriptParser {
             eprintln!("Found imports in {:?}: {:?}", path, imports);
         }
 
+        let resolved_imports: Vec<_> = imports
+            .into_iter()
+            .map(|import_path| {
+                let resolution = self.resolve_import(path, &import_path);
+                (import_path, resolution)
+            })
+            .collect();
+
+        // Names used in types are resolved through the file's imports and
+        // declarations. Packages that cannot be resolved may be declared by
+        // ambient modules such as `declare module 'pkg'`
+        self.current_module = if is_global {
+            PathBuf::new()
+        } else {
+            canonical_path
+        };
+        self.module_paths = resolved_imports
+            .iter()
+            .filter_map(|(import_path, resolution)| {
+                let module = match resolution {
+                    Ok(resolved_path) => resolved_path
+                        .canonicalize()
+                        .unwrap_or_else(|_| resolved_path.clone()),
+                    Err(_) if is_package_specifier(import_path) => PathBuf::from(import_path),
+                    Err(_) => return None,
+                };
+                Some((import_path.clone(), module))
+            })
+            .collect();
+        self.collect_scope(&result.program);
+
         self.process_program(&result.program, &file_path_str);
 
         // Parse imported files
-        for import_path in imports {
-            match self.resolve_import(path, &import_path) {
+        for (import_path, resolution) in resolved_imports {
+            match resolution {
                 Ok(resolved_path) => {
                     if std::env::var("BAGSAKAN_DEBUG").is_ok() {
                         eprintln!("Resolved '{}' to {:?}", import_path, resolved_path);
@@ -225,6 +417,8 @@ impl TypeScriptParser {
             }
         }
 
+        self.resolve_enum_references();
+
         Ok(())
     }
 
@@ -247,12 +441,631 @@ impl TypeScriptParser {
                     }
                     _ => {}
                 }
+            } else if let Statement::TSImportEqualsDeclaration(import) = stmt {
+                if let TSModuleReference::ExternalModuleReference(reference) =
+                    &import.module_reference
+                {
+                    imports.push(reference.expression.value.as_str().to_string());
+                }
+            }
+        }
+
+        // Module augmentations such as `declare module './user'` extend the
+        // declarations of the augmented module, and ambient modules such as
+        // `declare module 'pkg'` in declaration files declare them
+        for stmt in &program.body {
+            if let Statement::TSModuleDeclaration(module) = stmt {
+                if let TSModuleDeclarationName::StringLiteral(source) = &module.id {
+                    imports.push(source.value.as_str().to_string());
+                }
             }
         }
 
+        // Types can also be referenced without an import declaration, as in
+        // `import('./models').User`
+        let mut import_types = ImportTypeCollector::default();
+        import_types.visit_program(program);
+        imports.extend(import_types.sources);
+
         imports
     }
 
+    /// Records the types that names refer to in a file: the types it declares
+    /// and the types it imports, along with the types it re-exports
+    fn collect_scope(&mut self, program: &Program) {
+        let mut scope = HashMap::new();
+        let mut exports = Vec::new();
+
+        for stmt in &program.body {
+            let declaration = match stmt {
+                Statement::ImportDeclaration(import) => {
+                    let (Some(module), Some(specifiers)) = (
+                        self.module_paths.get(import.source.value.as_str()),
+                        &import.specifiers,
+                    ) else {
+                        continue;
+                    };
+                    for specifier in specifiers {
+                        let binding = match specifier {
+                            ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                                TypeBinding::Declaration {
+                                    module: module.clone(),
+                                    name: specifier.imported.name().as_str().to_string(),
+                                }
+                            }
+                            ImportDeclarationSpecifier::ImportDefaultSpecifier(_) => {
+                                TypeBinding::Declaration {
+                                    module: module.clone(),
+                                    name: "default".to_string(),
+                                }
+                            }
+                            ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => {
+                                TypeBinding::Namespace {
+                                    module: module.clone(),
+                                }
+                            }
+                        };
+                        scope.insert(specifier.local().name.as_str().to_string(), binding);
+                    }
+                    continue;
+                }
+                // `import Api = require('./api')` is used like a namespace import
+                Statement::TSImportEqualsDeclaration(import) => {
+                    if let TSModuleReference::ExternalModuleReference(reference) =
+                        &import.module_reference
+                    {
+                        if let Some(module) =
+                            self.module_paths.get(reference.expression.value.as_str())
+                        {
+                            scope.insert(
+                                import.id.name.as_str().to_string(),
+                                TypeBinding::Namespace {
+                                    module: module.clone(),
+                                },
+                            );
+                        }
+                    }
+                    continue;
+                }
+                Statement::TSExportAssignment(export) => {
+                    if let Expression::Identifier(id) = &export.expression {
+                        exports.push(ModuleExport::Equals {
+                            local: id.name.as_str().to_string(),
+                        });
+                    }
+                    continue;
+                }
+                Statement::ExportAllDeclaration(export) if export.exported.is_none() => {
+                    if let Some(module) = self.module_paths.get(export.source.value.as_str()) {
+                        exports.push(ModuleExport::All {
+                            module: module.clone(),
+                        });
+                    }
+                    continue;
+                }
+                // `export default User` exports a declared or imported type, and
+                // `export default interface User {}` declares one
+                Statement::ExportDefaultDeclaration(export) => {
+                    let local = match &export.declaration {
+                        ExportDefaultDeclarationKind::Identifier(id) => &id.name,
+                        ExportDefaultDeclarationKind::TSInterfaceDeclaration(interface) => {
+                            &interface.id.name
+                        }
+                        ExportDefaultDeclarationKind::ClassDeclaration(class) => match &class.id {
+                            Some(id) => &id.name,
+                            None => continue,
+                        },
+                        _ => continue,
+                    };
+                    if !matches!(
+                        export.declaration,
+                        ExportDefaultDeclarationKind::Identifier(_)
+                    ) {
+                        scope.insert(
+                            local.as_str().to_string(),
+                            TypeBinding::Declaration {
+                                module: self.current_module.clone(),
+                                name: local.as_str().to_string(),
+                            },
+                        );
+                    }
+                    exports.push(ModuleExport::Named {
+                        name: "default".to_string(),
+                        module: self.current_module.clone(),
+                        local: local.as_str().to_string(),
+                    });
+                    continue;
+                }
+                Statement::ExportNamedDeclaration(export) => {
+                    let source = export
+                        .source
+                        .as_ref()
+                        .and_then(|source| self.module_paths.get(source.value.as_str()));
+                    for specifier in &export.specifiers {
+                        exports.push(ModuleExport::Named {
+                            name: specifier.exported.name().as_str().to_string(),
+                            module: match source {
+                                Some(module) => module.clone(),
+                                None => self.current_module.clone(),
+                            },
+                            local: specifier.local.name().as_str().to_string(),
+                        });
+                    }
+                    match &export.declaration {
+                        Some(declaration) => declaration,
+                        None => continue,
+                    }
+                }
+                _ => match stmt.as_declaration() {
+                    Some(declaration) => declaration,
+                    None => continue,
+                },
+            };
+            let exported = matches!(stmt, Statement::ExportNamedDeclaration(_));
+
+            // Constants are in scope for the `typeof` queries naming them
+            let names: Vec<&Atom> = match declaration {
+                Declaration::VariableDeclaration(var_decl) => var_decl
+                    .declarations
+                    .iter()
+                    .filter_map(|decl| decl.id.get_binding_identifier())
+                    .map(|id| &id.name)
+                    .collect(),
+                _ => get_declaration_name(declaration).into_iter().collect(),
+            };
+            for name in names {
+                scope.insert(
+                    name.as_str().to_string(),
+                    TypeBinding::Declaration {
+                        module: self.current_module.clone(),
+                        name: name.as_str().to_string(),
+                    },
+                );
+                if exported {
+                    exports.push(ModuleExport::Named {
+                        name: name.as_str().to_string(),
+                        module: self.current_module.clone(),
+                        local: name.as_str().to_string(),
+                    });
+                }
+            }
+        }
+
+        // `export { User }` re-exports an imported type from the module it is
+        // imported from
+        for export in &mut exports {
+            if let ModuleExport::Named { module, local, .. } = export {
+                if *module != self.current_module {
+                    continue;
+                }
+                if let Some(TypeBinding::Declaration {
+                    module: source,
+                    name,
+                }) = scope.get(local.as_str())
+                {
+                    *module = source.clone();
+                    *local = name.clone();
+                }
+            }
+        }
+        if !exports.is_empty() {
+            self.module_exports
+                .insert(self.current_module.clone(), exports);
+        }
+        self.scope = scope;
+    }
+
+    /// Returns the key a type declared in a module is registered under
+    fn declaration_key(&mut self, module: &Path, name: &str) -> String {
+        let id = (module.to_path_buf(), name.to_string());
+        if let Some(key) = self.declaration_keys.get(&id) {
+            return key.clone();
+        }
+
+        // Members of namespaces are keyed under the key of their namespace
+        let name = match name.rsplit_once('.') {
+            Some((namespace, member)) => {
+                format!("{}.{}", self.declaration_key(module, namespace), member)
+            }
+            None => name.to_string(),
+        };
+        let mut key = name.clone();
+        let mut suffix = 2;
+        while self.type_keys.contains(&key) {
+            key = format!("{}{}", name, suffix);
+            suffix += 1;
+        }
+        self.type_keys.insert(key.clone());
+        self.declaration_keys.insert(id, key.clone());
+        key
+    }
+
+    /// Returns the key and the qualified name of a type declared in the module
+    /// or namespace being processed
+    fn declare(&mut self, name: &str) -> (String, String) {
+        let declared_name = match &self.current_namespace {
+            Some(namespace) => format!("{}.{}", namespace, name),
+            None => name.to_string(),
+        };
+        let key = self.declaration_key(&self.current_module.clone(), &declared_name);
+        (key, declared_name)
+    }
+
+    /// Replaces the names of types declared in or imported into the file being
+    /// parsed with references to their declarations, so that types with the
+    /// same name in different modules stay distinct. Names that are not in
+    /// scope, such as `Date` or type parameters, are kept as they are
+    fn qualify_type_references(&mut self, type_str: &str, type_parameters: &[String]) -> String {
+        let chars: Vec<char> = type_str.chars().collect();
+        let is_name_char = |c: char| c.is_alphanumeric() || c == '_' || c == '$';
+        let mut result = String::new();
+        let mut quote = None;
+        let mut i = 0;
+
+        while i < chars.len() {
+            let c = chars[i];
+            if let Some(quote_char) = quote {
+                // Types in template literal types are qualified like others
+                if quote_char == '`' && c == '$' && chars.get(i + 1) == Some(&'{') {
+                    let mut depth = 0;
+                    let end = (i + 1..chars.len())
+                        .find(|&j| {
+                            match chars[j] {
+                                '{' => depth += 1,
+                                '}' => depth -= 1,
+                                _ => {}
+                            }
+                            depth == 0
+                        })
+                        .unwrap_or(chars.len() - 1);
+                    let inner: String = chars[i + 2..end].iter().collect();
+                    result.push_str("${");
+                    result.push_str(&self.qualify_type_references(&inner, type_parameters));
+                    result.push('}');
+                    i = end + 1;
+                    continue;
+                }
+                result.push(c);
+                if c == '\\' && i + 1 < chars.len() {
+                    result.push(chars[i + 1]);
+                    i += 1;
+                } else if c == quote_char {
+                    quote = None;
+                }
+                i += 1;
+                continue;
+            }
+            if matches!(c, '\'' | '"' | '`') {
+                quote = Some(c);
+                result.push(c);
+                i += 1;
+                continue;
+            }
+            if !(c.is_alphabetic() || c == '_' || c == '$') || (i > 0 && is_name_char(chars[i - 1]))
+            {
+                result.push(c);
+                i += 1;
+                continue;
+            }
+
+            // Read a possibly qualified name such as `Api.Order`
+            let start = i;
+            while i < chars.len()
+                && (is_name_char(chars[i])
+                    || (chars[i] == '.' && chars.get(i + 1).is_some_and(|c| c.is_alphabetic())))
+            {
+                i += 1;
+            }
+            let path: String = chars[start..i].iter().collect();
+
+            // Import types such as `import('./models').User` name a type
+            // exported from the imported module
+            if path == "import" && chars.get(i) == Some(&'(') {
+                if let Some((source, name, end)) = parse_import_type(&chars, i) {
+                    match self.module_paths.get(&source) {
+                        Some(module) => {
+                            result
+                                .push_str(&format!("__type_ref_{}__", self.type_references.len()));
+                            self.type_references.push((module.clone(), name));
+                        }
+                        // Modules that cannot be resolved leave the type to be
+                        // found by name like other types
+                        None => result.push_str(name.rsplit('.').next().unwrap_or(&name)),
+                    }
+                    i = end;
+                    continue;
+                }
+            }
+
+            // Property keys are not types, while the constants named by
+            // `typeof` are resolved through the file's scope like types
+            let next = if chars.get(i) == Some(&'?') { i + 1 } else { i };
+            if chars.get(next) == Some(&':') {
+                result.push_str(&path);
+                continue;
+            }
+            let qualified = self.qualify_type_name(&path, type_parameters);
+            if qualified == path && chars.get(i) == Some(&'<') {
+                // Generic types that are not in scope are found by name
+                result.push_str(path.rsplit('.').next().unwrap_or(&path));
+            } else {
+                result.push_str(&qualified);
+            }
+        }
+
+        result
+    }
+
+    fn qualify_type_name(&mut self, path: &str, type_parameters: &[String]) -> String {
+        let mut segments = path.splitn(2, '.');
+        let name = segments.next().unwrap_or(path);
+        let rest = segments.next();
+        if type_parameters.iter().any(|param| param == name) {
+            return path.to_string();
+        }
+
+        // The rest of a qualified name may name a namespace member or an enum
+        // member, which is told apart once the declarations are known
+        let (module, name) = match (self.scope.get(name), rest) {
+            (Some(TypeBinding::Declaration { module, name }), Some(rest)) => {
+                (module.clone(), format!("{}.{}", name, rest))
+            }
+            (Some(TypeBinding::Declaration { module, name }), None) => {
+                (module.clone(), name.clone())
+            }
+            // Members of namespace imports are declared in the imported module
+            (Some(TypeBinding::Namespace { module }), Some(rest)) => {
+                (module.clone(), rest.to_string())
+            }
+            // Global types are declared in no module
+            (None, _)
+                if self
+                    .declaration_keys
+                    .contains_key(&(PathBuf::new(), name.to_string())) =>
+            {
+                (PathBuf::new(), path.to_string())
+            }
+            _ => return path.to_string(),
+        };
+
+        let reference = format!("__type_ref_{}__", self.type_references.len());
+        self.type_references.push((module, name));
+        reference
+    }
+
+    fn qualify_properties(
+        &mut self,
+        properties: Vec<PropertyInfo>,
+        type_parameters: &[String],
+    ) -> Vec<PropertyInfo> {
+        properties
+            .into_iter()
+            .map(|prop| PropertyInfo {
+                // Index signatures record the key type in the name, as in `[key: K]`
+                name: if prop.name.starts_with("[key: ") {
+                    self.qualify_type_references(&prop.name, type_parameters)
+                } else {
+                    prop.name
+                },
+                type_annotation: self
+                    .qualify_type_references(&prop.type_annotation, type_parameters),
+                optional: prop.optional,
+            })
+            .collect()
+    }
+
+    fn qualify_type_parameters(
+        &mut self,
+        type_parameters: Vec<TypeParameterInfo>,
+    ) -> Vec<TypeParameterInfo> {
+        let names: Vec<String> = type_parameters.iter().map(|p| p.name.clone()).collect();
+        type_parameters
+            .into_iter()
+            .map(|param| TypeParameterInfo {
+                constraint: param
+                    .constraint
+                    .map(|constraint| self.qualify_type_references(&constraint, &names)),
+                default: param
+                    .default
+                    .map(|default| self.qualify_type_references(&default, &names)),
+                name: param.name,
+            })
+            .collect()
+    }
+
+    /// Qualifies a base type such as `Api.Base<T>`. Bases that are not in
+    /// scope are found by their rightmost name like other types
+    fn qualify_base_type(
+        &mut self,
+        base_name: &str,
+        type_arguments: &str,
+        type_parameters: &[String],
+    ) -> String {
+        let mut base = self.qualify_type_name(base_name, type_parameters);
+        if base == base_name {
+            base = base_name
+                .rsplit('.')
+                .next()
+                .unwrap_or(base_name)
+                .to_string();
+        }
+        format!(
+            "{}{}",
+            base,
+            self.qualify_type_references(type_arguments, type_parameters)
+        )
+    }
+
+    /// Replaces the references to declarations in the types recorded so far
+    /// with the keys of the declarations
+    fn resolve_type_references(&mut self) {
+        let keys: Vec<String> = self
+            .type_references
+            .iter()
+            .map(|(module, name)| self.resolve_qualified_name(module, name))
+            .collect();
+        let reference = Regex::new(r"__type_ref_(\d+)__").unwrap();
+        let resolve = |type_str: &mut String| {
+            if type_str.contains("__type_ref_") {
+                *type_str = reference
+                    .replace_all(type_str, |captures: &regex::Captures| {
+                        keys[captures[1].parse::<usize>().unwrap()].clone()
+                    })
+                    .into_owned();
+            }
+        };
+
+        for interface in self.interfaces.values_mut() {
+            for prop in &mut interface.properties {
+                resolve(&mut prop.name);
+                resolve(&mut prop.type_annotation);
+            }
+            interface.extends.iter_mut().for_each(resolve);
+            for param in &mut interface.type_parameters {
+                param.constraint.iter_mut().for_each(resolve);
+                param.default.iter_mut().for_each(resolve);
+            }
+        }
+        for alias in self.type_aliases.values_mut() {
+            resolve(&mut alias.type_annotation);
+            for param in &mut alias.type_parameters {
+                param.constraint.iter_mut().for_each(resolve);
+                param.default.iter_mut().for_each(resolve);
+            }
+        }
+        for constant in self.constants.values_mut() {
+            resolve(&mut constant.type_annotation);
+        }
+        for validator in &mut self.validator_functions {
+            resolve(&mut validator.interface_name);
+
+            // `validateApiOrder` validates `Api.Order` unless a type is named
+            // `ApiOrder`. When several namespaced types share the validator name,
+            // the first key in order is chosen so that runs agree
+            if !self.type_keys.contains(&validator.interface_name) {
+                if let Some(key) = self
+                    .type_keys
+                    .iter()
+                    .filter(|key| {
+                        key.contains('.') && validator_type_name(key) == validator.interface_name
+                    })
+                    .min()
+                {
+                    validator.interface_name = key.clone();
+                }
+            }
+        }
+    }
+
+    /// Records the names types are exported as by their own modules when they
+    /// are not exported under the names they are declared with
+    fn collect_export_names(&mut self) {
+        let mut exported_as_declared = HashSet::new();
+        let mut renamed = Vec::new();
+        for (exporter, exports) in &self.module_exports {
+            for export in exports {
+                let (name, module, local) = match export {
+                    ModuleExport::Named {
+                        name,
+                        module,
+                        local,
+                    } => (name.as_str(), module, local),
+                    ModuleExport::Equals { local } => ("=", exporter, local),
+                    ModuleExport::All { .. } => continue,
+                };
+                // Exports from other modules are re-exports
+                if module != exporter {
+                    continue;
+                }
+                let Some(key) = self.declaration_keys.get(&(module.clone(), local.clone())) else {
+                    continue;
+                };
+                if name == local {
+                    exported_as_declared.insert(key.clone());
+                } else {
+                    renamed.push((key.clone(), name.to_string()));
+                }
+            }
+        }
+
+        for (key, name) in renamed {
+            if exported_as_declared.contains(&key) {
+                continue;
+            }
+            // Named exports are preferred over default exports
+            let export_name = self.export_names.entry(key).or_insert(name.clone());
+            if export_name == "default" {
+                *export_name = name;
+            }
+        }
+    }
+
+    /// Resolves a possibly qualified name such as `Api.Order` or
+    /// `Status.Pending`, keeping the members of resolved types such as enum
+    /// members. Names that cannot be resolved are kept as they are
+    fn resolve_qualified_name(&self, module: &PathBuf, name: &str) -> String {
+        let mut prefix = name;
+        loop {
+            if let Some(key) = self.resolve_declaration(module, prefix, &mut HashSet::new()) {
+                return format!("{}{}", key, &name[prefix.len()..]);
+            }
+            match prefix.rsplit_once('.') {
+                Some((shorter, _)) => prefix = shorter,
+                None => return name.to_string(),
+            }
+        }
+    }
+
+    /// Finds the key of a type exported from a module, following re-exports
+    fn resolve_declaration(
+        &self,
+        module: &PathBuf,
+        name: &str,
+        visited: &mut HashSet<PathBuf>,
+    ) -> Option<String> {
+        if let Some(key) = self
+            .declaration_keys
+            .get(&(module.clone(), name.to_string()))
+        {
+            return Some(key.clone());
+        }
+        if !visited.insert(module.clone()) {
+            return None;
+        }
+
+        // Members of exported namespaces are found through the namespace
+        let (head, rest) = match name.split_once('.') {
+            Some((head, rest)) => (head, Some(rest)),
+            None => (name, None),
+        };
+        let qualify = |local: &str| match rest {
+            Some(rest) => format!("{}.{}", local, rest),
+            None => local.to_string(),
+        };
+
+        self.module_exports
+            .get(module)?
+            .iter()
+            .find_map(|export| match export {
+                ModuleExport::Named {
+                    name: exported,
+                    module: source,
+                    local,
+                } if exported == head => self.resolve_declaration(source, &qualify(local), visited),
+                ModuleExport::All { module: source } => {
+                    self.resolve_declaration(source, name, visited)
+                }
+                // `export = Api` is also the default export of the module
+                ModuleExport::Equals { local } if head == "default" => {
+                    self.resolve_declaration(module, &qualify(local), visited)
+                }
+                ModuleExport::Equals { local } => {
+                    self.resolve_declaration(module, &format!("{}.{}", local, name), visited)
+                }
+                _ => None,
+            })
+    }
+
     pub fn resolve_import(
         &self,
         current_file: &Path,
@@ -314,6 +1127,19 @@ impl TypeScriptParser {
     fn process_program(&mut self, program: &Program, file_path: &str) {
         for stmt in &program.body {
             self.process_statement(stmt, file_path);
+
+            // Only module level constants can be referenced by types in other declarations
+            let var_decl = match stmt {
+                Statement::VariableDeclaration(var_decl) => Some(var_decl),
+                Statement::ExportNamedDeclaration(export) => match &export.declaration {
+                    Some(Declaration::VariableDeclaration(var_decl)) => Some(var_decl),
+                    _ => None,
+                },
+                _ => None,
+            };
+            if let Some(var_decl) = var_decl {
+                self.process_constants(var_decl, file_path);
+            }
         }
     }
 
@@ -358,10 +1184,24 @@ impl TypeScriptParser {
                                 }
                             }
                         }
-                        ModuleDeclaration::ExportDefaultDeclaration(_export) => {
-                            // Handle default exports if needed
-                            if std::env::var("BAGSAKAN_DEBUG").is_ok() {
-                                eprintln!("Default export found in {}", file_path);
+                        ModuleDeclaration::ExportDefaultDeclaration(export) => {
+                            match &export.declaration {
+                                ExportDefaultDeclarationKind::TSInterfaceDeclaration(interface) => {
+                                    self.process_interface(interface, file_path);
+                                }
+                                ExportDefaultDeclarationKind::ClassDeclaration(class) => {
+                                    self.process_class(class, file_path);
+                                }
+                                ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
+                                    if let Some(body) = &func.body {
+                                        self.process_function_body(body);
+                                    }
+                                }
+                                declaration => {
+                                    if let Some(expr) = declaration.as_expression() {
+                                        self.process_expression(expr);
+                                    }
+                                }
                             }
                         }
                         _ => {}
@@ -376,8 +1216,11 @@ impl TypeScriptParser {
             Declaration::TSInterfaceDeclaration(interface) => {
                 self.process_interface(interface, file_path);
             }
+            Declaration::TSTypeAliasDeclaration(alias) => {
+                self.process_type_alias(alias, file_path);
+            }
             Declaration::TSEnumDeclaration(enum_decl) => {
-                self.process_enum(enum_decl);
+                self.process_enum(enum_decl, file_path);
             }
             Declaration::FunctionDeclaration(func) => {
                 // Check function body for validator calls
@@ -386,77 +1229,304 @@ impl TypeScriptParser {
                 }
             }
             Declaration::ClassDeclaration(class) => {
-                self.process_class(class);
+                self.process_class(class, file_path);
             }
             Declaration::VariableDeclaration(var_decl) => {
                 self.process_variable_declaration(var_decl);
             }
+            // `declare global { ... }` and `declare module './user' { ... }`
+            // add declarations to existing scopes
+            Declaration::TSModuleDeclaration(module)
+                if module.kind == TSModuleDeclarationKind::Global
+                    || matches!(module.id, TSModuleDeclarationName::StringLiteral(_)) =>
+            {
+                if let Some(TSModuleDeclarationBody::TSModuleBlock(block)) = &module.body {
+                    // Declarations are added to the augmented module, or to
+                    // no module for global declarations. Types of modules that
+                    // cannot be resolved are imported from the module itself
+                    let (augmented_module, file_path) = match &module.id {
+                        TSModuleDeclarationName::StringLiteral(specifier) => {
+                            match self.module_paths.get(specifier.value.as_str()) {
+                                Some(path) if path.is_absolute() => (path.clone(), file_path),
+                                _ => (
+                                    PathBuf::from(specifier.value.as_str()),
+                                    specifier.value.as_str(),
+                                ),
+                            }
+                        }
+                        TSModuleDeclarationName::Identifier(_) => (PathBuf::new(), ""),
+                    };
+                    let module = std::mem::replace(&mut self.current_module, augmented_module);
+                    let was_in_augmentation = self.in_augmentation;
+                    self.in_augmentation = true;
+                    for stmt in &block.body {
+                        self.process_statement(stmt, file_path);
+                    }
+                    self.in_augmentation = was_in_augmentation;
+                    self.current_module = module;
+                }
+            }
+            Declaration::TSModuleDeclaration(namespace) => {
+                self.process_namespace(namespace, file_path);
+            }
             _ => {}
         }
     }
 
-    fn process_enum(&mut self, enum_decl: &TSEnumDeclaration) {
+    /// Processes `namespace Api { ... }`, whose members are declared under
+    /// qualified names such as `Api.Order`
+    fn process_namespace(&mut self, namespace: &TSModuleDeclaration, file_path: &str) {
+        let TSModuleDeclarationName::Identifier(id) = &namespace.id else {
+            return;
+        };
+        let (_, name) = self.declare(id.name.as_str());
+        let parent = self.current_namespace.replace(name.clone());
+
+        match &namespace.body {
+            Some(TSModuleDeclarationBody::TSModuleBlock(block)) => {
+                // Members refer to each other by their unqualified names
+                let mut shadowed = Vec::new();
+                for stmt in &block.body {
+                    let declaration = match stmt {
+                        Statement::ExportNamedDeclaration(export) => export.declaration.as_ref(),
+                        _ => stmt.as_declaration(),
+                    };
+                    if let Some(member) = declaration.and_then(get_declaration_name) {
+                        let binding = TypeBinding::Declaration {
+                            module: self.current_module.clone(),
+                            name: format!("{}.{}", name, member),
+                        };
+                        let member = member.as_str().to_string();
+                        let previous = self.scope.insert(member.clone(), binding);
+                        shadowed.push((member, previous));
+                    }
+                }
+
+                for stmt in &block.body {
+                    self.process_statement(stmt, file_path);
+                }
+
+                for (member, previous) in shadowed.into_iter().rev() {
+                    match previous {
+                        Some(binding) => self.scope.insert(member, binding),
+                        None => self.scope.remove(&member),
+                    };
+                }
+            }
+            // `namespace Api.V1 { ... }` nests `V1` in `Api`
+            Some(TSModuleDeclarationBody::TSModuleDeclaration(inner)) => {
+                self.process_namespace(inner, file_path);
+            }
+            None => {}
+        }
+
+        self.current_namespace = parent;
+    }
+
+    fn process_enum(&mut self, enum_decl: &TSEnumDeclaration, file_path: &str) {
         let enum_name = enum_decl.id.name.as_str().to_string();
-        let mut members = Vec::new();
-        let mut next_numeric_value = 0.0;
+        let (enum_key, declared_name) = self.declare(&enum_name);
+        let mut members: Vec<EnumMember> = Vec::new();
+        let mut next_numeric_value = Some(0.0);
 
         for member in &enum_decl.body.members {
             let value = if let Some(init) = &member.initializer {
-                match init {
-                    Expression::StringLiteral(lit) => {
-                        EnumValue::String(lit.value.as_str().to_string())
-                    }
-                    Expression::NumericLiteral(lit) => {
-                        next_numeric_value = lit.value + 1.0;
-                        EnumValue::Number(lit.value)
+                let lookup = |name: &str| self.lookup_enum(name);
+                let value = evaluate_enum_expression(init, &enum_name, &members, &lookup);
+                if value.is_none() {
+                    // References to enums that have not been parsed yet are
+                    // resolved once the file's imports have been followed
+                    if let Some((target_enum, target_member)) = get_enum_member_reference(init) {
+                        let (target_module, target_enum) = match self.scope.get(&target_enum) {
+                            Some(TypeBinding::Declaration { module, name }) => {
+                                (module.clone(), name.clone())
+                            }
+                            _ => (self.current_module.clone(), target_enum),
+                        };
+                        self.pending_enum_references.push(EnumReference {
+                            enum_key: enum_key.clone(),
+                            member_index: members.len(),
+                            target_module,
+                            target_enum,
+                            target_member,
+                        });
                     }
-                    _ => EnumValue::Computed,
                 }
+                value
+            } else if enum_decl.declare && !enum_decl.r#const {
+                // Members of ambient enums without an initializer are computed
+                None
             } else {
-                // For numeric enums without initializers, use auto-increment
-                let current_value = next_numeric_value;
-                next_numeric_value += 1.0;
-                EnumValue::Number(current_value)
+                // Members without an initializer follow the previous numeric member
+                next_numeric_value.map(EnumValue::Number)
             };
 
-            members.push(EnumMember { value });
+            next_numeric_value = match &value {
+                Some(EnumValue::Number(n)) => Some(n + 1.0),
+                _ => None,
+            };
+            members.push(EnumMember {
+                name: member.id.static_name().as_str().to_string(),
+                value: value.unwrap_or(EnumValue::Computed),
+            });
         }
 
-        self.enums.insert(enum_name, EnumInfo { members });
+        self.enums.insert(
+            enum_key,
+            EnumInfo {
+                declared_name,
+                members,
+                file_path: file_path.to_string(),
+            },
+        );
     }
 
-    fn process_interface(&mut self, interface: &TSInterfaceDeclaration, file_path: &str) {
-        let interface_name = interface.id.name.as_str().to_string();
-        let mut properties = Vec::new();
+    /// Finds the enum a name refers to in the file being parsed
+    fn lookup_enum(&self, name: &str) -> Option<&EnumInfo> {
+        let key = match self.scope.get(name) {
+            Some(TypeBinding::Declaration { module, name }) => {
+                self.resolve_declaration(module, name, &mut HashSet::new())?
+            }
+            _ => name.to_string(),
+        };
+        self.enums.get(&key)
+    }
 
-        let body = &interface.body;
-        for member in &body.body {
-            if let TSSignature::TSPropertySignature(prop) = member {
-                let prop_name = match &prop.key {
-                    PropertyKey::StaticIdentifier(id) => id.name.as_str().to_string(),
-                    PropertyKey::Identifier(id) => id.name.as_str().to_string(),
-                    _ => continue,
-                };
+    /// Resolves enum members initialized from members of enums that were
+    /// declared later or in another file, such as `Admin = Role.Admin`
+    fn resolve_enum_references(&mut self) {
+        loop {
+            let pending = std::mem::take(&mut self.pending_enum_references);
+            let pending_count = pending.len();
+
+            for reference in pending {
+                let target_key = self
+                    .resolve_declaration(
+                        &reference.target_module,
+                        &reference.target_enum,
+                        &mut HashSet::new(),
+                    )
+                    .unwrap_or_else(|| reference.target_enum.clone());
+                let value = self
+                    .enums
+                    .get(&target_key)
+                    .and_then(|info| {
+                        info.members
+                            .iter()
+                            .find(|member| member.name == reference.target_member)
+                    })
+                    .map(|member| member.value.clone());
+
+                match (value, self.enums.get_mut(&reference.enum_key)) {
+                    (Some(value), Some(info)) if !matches!(value, EnumValue::Computed) => {
+                        info.members[reference.member_index].value = value;
+                    }
+                    _ => self.pending_enum_references.push(reference),
+                }
+            }
 
-                let type_str = if let Some(type_ann) = &prop.type_annotation {
-                    get_type_string(&type_ann.type_annotation)
-                } else {
-                    "any".to_string()
-                };
+            if self.pending_enum_references.len() == pending_count {
+                break;
+            }
+        }
+    }
 
-                properties.push(PropertyInfo {
-                    name: prop_name,
-                    type_annotation: type_str,
-                    optional: prop.optional,
-                });
+    fn process_interface(&mut self, interface: &TSInterfaceDeclaration, file_path: &str) {
+        let interface_name = interface.id.name.as_str().to_string();
+        let type_parameters = collect_type_parameters(&interface.type_parameters);
+        let parameter_names: Vec<String> = type_parameters.iter().map(|p| p.name.clone()).collect();
+
+        // Base interfaces are recorded by name and merged in during generation,
+        // since they may be declared in files that have not been parsed yet
+        let extends = interface
+            .extends
+            .iter()
+            .filter_map(|heritage| {
+                let base_name = get_expression_path(&heritage.expression)?;
+                Some(self.qualify_base_type(
+                    &base_name,
+                    &get_type_arguments_string(&heritage.type_arguments),
+                    &parameter_names,
+                ))
+            })
+            .collect();
+
+        let properties =
+            self.qualify_properties(collect_properties(&interface.body.body), &parameter_names);
+        let type_parameters = self.qualify_type_parameters(type_parameters);
+        let (key, declared_name) = self.declare(&interface_name);
+        self.register_interface(InterfaceInfo {
+            name: key,
+            declared_name,
+            type_parameters,
+            properties,
+            extends,
+            callable: is_callable(&interface.body.body),
+            is_class: false,
+            file_path: file_path.to_string(),
+        });
+    }
+
+    /// Records an interface or class, merging it with earlier declarations of
+    /// the same type in the module or in augmentations of it, as TypeScript does
+    fn register_interface(&mut self, declaration: InterfaceInfo) {
+        let name = declaration.name.clone();
+        let Some(existing) = self.interfaces.get_mut(&name) else {
+            if self.in_augmentation {
+                self.augmentation_only_interfaces.insert(name.clone());
             }
+            self.interfaces.insert(name, declaration);
+            return;
+        };
+
+        if self.in_augmentation {
+            merge_interface_declaration(existing, declaration);
+        } else if self.augmentation_only_interfaces.remove(&name) {
+            // The augmented declaration comes first and is the one to import
+            // the type from
+            let augmentation = std::mem::replace(existing, declaration);
+            merge_interface_declaration(existing, augmentation);
+        } else {
+            merge_interface_declaration(existing, declaration);
         }
+    }
 
-        self.interfaces.insert(
-            interface_name.clone(),
-            InterfaceInfo {
-                name: interface_name,
-                properties,
+    fn process_type_alias(&mut self, alias: &TSTypeAliasDeclaration, file_path: &str) {
+        let alias_name = alias.id.name.as_str().to_string();
+        let (key, declared_name) = self.declare(&alias_name);
+        let type_parameters = collect_type_parameters(&alias.type_parameters);
+        let parameter_names: Vec<String> = type_parameters.iter().map(|p| p.name.clone()).collect();
+        let type_parameters = self.qualify_type_parameters(type_parameters);
+
+        // An alias of an object literal has the same shape as an interface
+        if let TSType::TSTypeLiteral(literal) = &alias.type_annotation {
+            let properties =
+                self.qualify_properties(collect_properties(&literal.members), &parameter_names);
+            self.interfaces.insert(
+                key.clone(),
+                InterfaceInfo {
+                    name: key,
+                    declared_name,
+                    type_parameters,
+                    properties,
+                    extends: Vec::new(),
+                    callable: is_callable(&literal.members),
+                    is_class: false,
+                    file_path: file_path.to_string(),
+                },
+            );
+            return;
+        }
+
+        let type_annotation = self
+            .qualify_type_references(&get_type_string(&alias.type_annotation), &parameter_names);
+        self.type_aliases.insert(
+            key.clone(),
+            TypeAliasInfo {
+                name: key,
+                declared_name,
+                type_parameters,
+                type_annotation,
                 file_path: file_path.to_string(),
             },
         );
@@ -477,9 +1547,11 @@ impl TypeScriptParser {
                     if let Some(interface_name) = captures.get(1) {
                         // Only collect validator functions from source files
                         if self.current_file_is_source {
+                            let interface_name =
+                                self.qualify_type_references(interface_name.as_str(), &[]);
                             self.validator_functions.push(ValidatorFunction {
                                 name: func_name.to_string(),
-                                interface_name: interface_name.as_str().to_string(),
+                                interface_name,
                             });
                         }
                     }
@@ -518,8 +1590,42 @@ impl TypeScriptParser {
         }
     }
 
-    fn process_class(&mut self, class: &Class) {
-        let body = &class.body;
+    fn process_class(&mut self, class: &Class, file_path: &str) {
+        if let Some(id) = &class.id {
+            // Classes are recorded like interfaces with the public instance
+            // fields of the class, including inherited ones. Methods and
+            // accessors live on the prototype, so values such as parsed JSON
+            // are only checked for the data the class holds
+            let class_name = id.name.as_str().to_string();
+            let type_parameters = collect_type_parameters(&class.type_parameters);
+            let parameter_names: Vec<String> =
+                type_parameters.iter().map(|p| p.name.clone()).collect();
+            let extends = match &class.super_class {
+                Some(Expression::Identifier(base)) => vec![self.qualify_base_type(
+                    base.name.as_str(),
+                    &get_type_arguments_string(&class.super_type_arguments),
+                    &parameter_names,
+                )],
+                _ => Vec::new(),
+            };
+
+            let properties =
+                self.qualify_properties(collect_class_properties(class), &parameter_names);
+            let type_parameters = self.qualify_type_parameters(type_parameters);
+            let (key, declared_name) = self.declare(&class_name);
+            self.register_interface(InterfaceInfo {
+                name: key,
+                declared_name,
+                type_parameters,
+                properties,
+                extends,
+                callable: false,
+                is_class: true,
+                file_path: file_path.to_string(),
+            });
+        }
+
+        let body = &class.body;
         for member in &body.body {
             match member {
                 ClassElement::MethodDefinition(method) => {
@@ -538,6 +1644,39 @@ impl TypeScriptParser {
         }
     }
 
+    fn process_constants(&mut self, var_decl: &VariableDeclaration, file_path: &str) {
+        if var_decl.kind != VariableDeclarationKind::Const {
+            return;
+        }
+
+        for decl in &var_decl.declarations {
+            let (BindingPatternKind::BindingIdentifier(id), Some(init)) =
+                (&decl.id.kind, &decl.init)
+            else {
+                continue;
+            };
+
+            // A declared type takes precedence over the type of the initializer
+            let type_annotation = match &decl.id.type_annotation {
+                Some(type_ann) => Some(get_type_string(&type_ann.type_annotation)),
+                None => get_expression_type_string(init, ConstContext::Declaration),
+            };
+
+            if let Some(type_annotation) = type_annotation {
+                let type_annotation = self.qualify_type_references(&type_annotation, &[]);
+                let (key, declared_name) = self.declare(id.name.as_str());
+                self.constants.insert(
+                    key,
+                    ConstantInfo {
+                        declared_name,
+                        type_annotation,
+                        file_path: file_path.to_string(),
+                    },
+                );
+            }
+        }
+    }
+
     fn process_variable_declaration(&mut self, var_decl: &VariableDeclaration) {
         for decl in &var_decl.declarations {
             if let VariableDeclarator {
@@ -590,6 +1729,230 @@ impl TypeScriptParser {
     }
 }
 
+fn collect_properties(signatures: &[TSSignature]) -> Vec<PropertyInfo> {
+    let mut properties = Vec::new();
+
+    for member in signatures {
+        match member {
+            TSSignature::TSPropertySignature(prop) => {
+                let Some(prop_name) = get_property_key_name(&prop.key) else {
+                    continue;
+                };
+
+                let type_str = if let Some(type_ann) = &prop.type_annotation {
+                    get_type_string(&type_ann.type_annotation)
+                } else {
+                    "any".to_string()
+                };
+
+                properties.push(PropertyInfo {
+                    name: prop_name,
+                    type_annotation: type_str,
+                    optional: prop.optional,
+                });
+            }
+            TSSignature::TSIndexSignature(index) => {
+                // Index signatures are kept as properties named after their key
+                // type, e.g. `[key: string]`, so they are inherited and merged
+                // together with regular properties
+                for parameter in &index.parameters {
+                    properties.push(PropertyInfo {
+                        name: format!(
+                            "[key: {}]",
+                            get_type_string(&parameter.type_annotation.type_annotation)
+                        ),
+                        type_annotation: get_type_string(&index.type_annotation.type_annotation),
+                        optional: false,
+                    });
+                }
+            }
+            TSSignature::TSMethodSignature(method) => {
+                let Some(name) = get_property_key_name(&method.key) else {
+                    continue;
+                };
+                let existing = properties.iter().position(|prop| prop.name == name);
+
+                match method.kind {
+                    // Getters are read like properties. Setters are skipped, as a
+                    // setter without a getter reads back as undefined
+                    TSMethodSignatureKind::Get => {
+                        let prop = PropertyInfo {
+                            name,
+                            type_annotation: method.return_type.as_ref().map_or_else(
+                                || "any".to_string(),
+                                |return_type| get_type_string(&return_type.type_annotation),
+                            ),
+                            optional: method.optional,
+                        };
+                        match existing {
+                            Some(index) => properties[index] = prop,
+                            None => properties.push(prop),
+                        }
+                    }
+                    // Overloads of a method share a single property
+                    TSMethodSignatureKind::Method if existing.is_none() => {
+                        properties.push(PropertyInfo {
+                            name,
+                            type_annotation: "Function".to_string(),
+                            optional: method.optional,
+                        });
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    properties
+}
+
+/// Merges another declaration of an interface into an existing one. Members
+/// that are already declared, such as method overloads, keep their first type
+fn merge_interface_declaration(existing: &mut InterfaceInfo, declaration: InterfaceInfo) {
+    for prop in declaration.properties {
+        if !existing.properties.iter().any(|p| p.name == prop.name) {
+            existing.properties.push(prop);
+        }
+    }
+    for base in declaration.extends {
+        if !existing.extends.contains(&base) {
+            existing.extends.push(base);
+        }
+    }
+    if existing.type_parameters.is_empty() {
+        existing.type_parameters = declaration.type_parameters;
+    }
+    existing.callable |= declaration.callable;
+    existing.is_class |= declaration.is_class;
+}
+
+/// Collects the public instance members of a class, including properties
+/// declared by constructor parameters such as `constructor(public id: string)`
+fn collect_class_properties(class: &Class) -> Vec<PropertyInfo> {
+    let mut properties: Vec<PropertyInfo> = Vec::new();
+    let is_public = |accessibility: Option<TSAccessibility>| {
+        !matches!(
+            accessibility,
+            Some(TSAccessibility::Private | TSAccessibility::Protected)
+        )
+    };
+    let annotation_string = |type_annotation: Option<&TSTypeAnnotation>| {
+        type_annotation.map_or_else(
+            || "any".to_string(),
+            |type_ann| get_type_string(&type_ann.type_annotation),
+        )
+    };
+
+    for member in &class.body.body {
+        match member {
+            ClassElement::PropertyDefinition(prop)
+                if !prop.r#static && is_public(prop.accessibility) =>
+            {
+                let Some(name) = get_property_key_name(&prop.key) else {
+                    continue;
+                };
+                // Fields without a type are inferred from their initializer
+                let type_annotation = match (&prop.type_annotation, &prop.value) {
+                    (None, Some(value)) => get_expression_type_string(value, ConstContext::Widened)
+                        .unwrap_or_else(|| "any".to_string()),
+                    (type_annotation, _) => annotation_string(type_annotation.as_deref()),
+                };
+                properties.push(PropertyInfo {
+                    name,
+                    type_annotation,
+                    optional: prop.optional,
+                });
+            }
+            ClassElement::MethodDefinition(method)
+                if method.kind == MethodDefinitionKind::Constructor =>
+            {
+                for param in &method.value.params.items {
+                    let BindingPatternKind::BindingIdentifier(id) = &param.pattern.kind else {
+                        continue;
+                    };
+                    // Only parameters with a modifier declare properties
+                    if (param.accessibility.is_some() || param.readonly)
+                        && is_public(param.accessibility)
+                    {
+                        properties.push(PropertyInfo {
+                            name: id.name.as_str().to_string(),
+                            type_annotation: annotation_string(
+                                param.pattern.type_annotation.as_deref(),
+                            ),
+                            optional: param.pattern.optional,
+                        });
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    properties
+}
+
+/// Whether an interface or object type declares call or construct signatures,
+/// making its values functions
+fn is_callable(signatures: &[TSSignature]) -> bool {
+    signatures.iter().any(|member| {
+        matches!(
+            member,
+            TSSignature::TSCallSignatureDeclaration(_)
+                | TSSignature::TSConstructSignatureDeclaration(_)
+        )
+    })
+}
+
+/// Returns the name of a property key, or `None` for computed keys such as
+/// `[Symbol.iterator]` which cannot be checked
+fn get_property_key_name(key: &PropertyKey) -> Option<String> {
+    match key {
+        PropertyKey::StaticIdentifier(id) => Some(id.name.as_str().to_string()),
+        PropertyKey::StringLiteral(s) => Some(s.value.as_str().to_string()),
+        // Numeric keys are named by the string they convert to, as in `1e21` for `1e+21`
+        PropertyKey::NumericLiteral(n) => Some(format_number(n.value)),
+        PropertyKey::TemplateLiteral(t) if t.expressions.is_empty() => Some(
+            t.quasis
+                .iter()
+                .filter_map(|quasi| quasi.value.cooked.as_ref())
+                .map(|cooked| cooked.as_str())
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
+fn collect_type_parameters(
+    type_parameters: &Option<oxc_allocator::Box<TSTypeParameterDeclaration>>,
+) -> Vec<TypeParameterInfo> {
+    let Some(type_parameters) = type_parameters else {
+        return Vec::new();
+    };
+
+    type_parameters
+        .params
+        .iter()
+        .map(|param| TypeParameterInfo {
+            name: param.name.name.as_str().to_string(),
+            constraint: param.constraint.as_ref().map(get_type_string),
+            default: param.default.as_ref().map(get_type_string),
+        })
+        .collect()
+}
+
+fn get_type_arguments_string(
+    type_arguments: &Option<oxc_allocator::Box<TSTypeParameterInstantiation>>,
+) -> String {
+    match type_arguments {
+        Some(type_args) => {
+            let arg_types: Vec<String> = type_args.params.iter().map(get_type_string).collect();
+            format!("<{}>", arg_types.join(", "))
+        }
+        None => String::new(),
+    }
+}
+
 fn get_type_string(ts_type: &TSType) -> String {
     match ts_type {
         TSType::TSStringKeyword(_) => "string".to_string(),
@@ -599,36 +1962,842 @@ fn get_type_string(ts_type: &TSType) -> String {
         TSType::TSVoidKeyword(_) => "void".to_string(),
         TSType::TSNullKeyword(_) => "null".to_string(),
         TSType::TSUndefinedKeyword(_) => "undefined".to_string(),
-        TSType::TSArrayType(arr) => format!("{}[]", get_type_string(&arr.element_type)),
+        TSType::TSBigIntKeyword(_) => "bigint".to_string(),
+        TSType::TSSymbolKeyword(_) => "symbol".to_string(),
+        TSType::TSObjectKeyword(_) => "object".to_string(),
+        TSType::TSUnknownKeyword(_) => "unknown".to_string(),
+        TSType::TSNeverKeyword(_) => "never".to_string(),
+        TSType::TSArrayType(arr) => {
+            format!("{}[]", get_parenthesized_type_string(&arr.element_type))
+        }
+        TSType::TSParenthesizedType(paren) => get_type_string(&paren.type_annotation),
+        TSType::TSTupleType(tuple) => {
+            let elements: Vec<String> = tuple
+                .element_types
+                .iter()
+                .map(get_tuple_element_string)
+                .collect();
+            format!("[{}]", elements.join(", "))
+        }
+        TSType::TSTypeOperatorType(operator) => match operator.operator {
+            TSTypeOperatorOperator::Keyof => format!(
+                "keyof {}",
+                get_parenthesized_type_string(&operator.type_annotation)
+            ),
+            // Readonly arrays and tuples are checked the same as mutable ones,
+            // and `unique symbol` is checked like any other symbol
+            TSTypeOperatorOperator::Readonly | TSTypeOperatorOperator::Unique => {
+                get_type_string(&operator.type_annotation)
+            }
+        },
+        TSType::TSTypeQuery(query) => match &query.expr_name {
+            TSTypeQueryExprName::IdentifierReference(id) => format!("typeof {}", id.name.as_str()),
+            TSTypeQueryExprName::QualifiedName(qualified) => {
+                format!("typeof {}", get_qualified_name_string(qualified))
+            }
+            _ => UNSUPPORTED_TYPE.to_string(),
+        },
+        TSType::TSIndexedAccessType(indexed) => format!(
+            "{}[{}]",
+            get_parenthesized_type_string(&indexed.object_type),
+            get_type_string(&indexed.index_type)
+        ),
         TSType::TSUnionType(union) => {
             let types: Vec<String> = union.types.iter().map(|t| get_type_string(t)).collect();
             types.join(" | ")
         }
+        TSType::TSIntersectionType(intersection) => {
+            let types: Vec<String> = intersection
+                .types
+                .iter()
+                .map(get_parenthesized_type_string)
+                .collect();
+            types.join(" & ")
+        }
+        TSType::TSTypeLiteral(literal) => get_type_literal_string(&literal.members),
+        // Function types are only checked to be functions
+        TSType::TSFunctionType(_) | TSType::TSConstructorType(_) => "Function".to_string(),
+        TSType::TSTemplateLiteralType(template) => get_template_literal_string(template),
         TSType::TSLiteralType(lit) => match &lit.literal {
-            TSLiteral::StringLiteral(s) => format!("'{}'", s.value.as_str()),
-            TSLiteral::NumericLiteral(n) => n.value.to_string(),
+            TSLiteral::StringLiteral(s) => quote_string(s.value.as_str()),
+            TSLiteral::NumericLiteral(n) => format_number(n.value),
             TSLiteral::BooleanLiteral(b) => b.value.to_string(),
-            _ => "unknown".to_string(),
+            TSLiteral::UnaryExpression(unary) if unary.operator == UnaryOperator::UnaryNegation => {
+                match &unary.argument {
+                    Expression::NumericLiteral(n) => format_number(-n.value),
+                    _ => UNSUPPORTED_TYPE.to_string(),
+                }
+            }
+            // A template without interpolated types is the same as a string literal
+            TSLiteral::TemplateLiteral(t) => quote_string(
+                &t.quasis
+                    .iter()
+                    .filter_map(|quasi| quasi.value.cooked.as_ref())
+                    .map(|cooked| cooked.as_str())
+                    .collect::<String>(),
+            ),
+            _ => UNSUPPORTED_TYPE.to_string(),
         },
+        // Qualified names are kept in full so the generator can tell enum
+        // members such as `Status.Pending` from namespace members
+        TSType::TSTypeReference(type_ref) if type_ref.type_arguments.is_none() => {
+            match &type_ref.type_name {
+                TSTypeName::QualifiedName(qualified) => get_qualified_name_string(qualified),
+                type_name => get_type_name_string(type_name)
+                    .map_or_else(|| UNSUPPORTED_TYPE.to_string(), str::to_string),
+            }
+        }
         TSType::TSTypeReference(type_ref) => {
-            if let TSTypeName::IdentifierReference(id) = &type_ref.type_name {
-                let base_type = id.name.as_str();
-
-                // Handle generic types with type arguments
-                if let Some(type_args) = &type_ref.type_arguments {
-                    let arg_types: Vec<String> = type_args
-                        .params
-                        .iter()
-                        .map(|param| get_type_string(param))
-                        .collect();
-                    format!("{}<{}>", base_type, arg_types.join(", "))
-                } else {
-                    base_type.to_string()
+            let name = match &type_ref.type_name {
+                TSTypeName::QualifiedName(qualified) => get_qualified_name_string(qualified),
+                type_name => match get_type_name_string(type_name) {
+                    Some(name) => name.to_string(),
+                    None => return UNSUPPORTED_TYPE.to_string(),
+                },
+            };
+            // Handle generic types with type arguments
+            format!(
+                "{}{}",
+                name,
+                get_type_arguments_string(&type_ref.type_arguments)
+            )
+        }
+        TSType::TSImportType(import_type) => {
+            // The module is kept so the type can be resolved against it once
+            // the imports of the file are known
+            let name = match &import_type.qualifier {
+                Some(TSTypeName::QualifiedName(qualified)) => get_qualified_name_string(qualified),
+                Some(type_name) => match get_type_name_string(type_name) {
+                    Some(name) => name.to_string(),
+                    None => return UNSUPPORTED_TYPE.to_string(),
+                },
+                None => return UNSUPPORTED_TYPE.to_string(),
+            };
+            match &import_type.argument {
+                TSType::TSLiteralType(literal) => match &literal.literal {
+                    TSLiteral::StringLiteral(source) => format!(
+                        "import({}).{}{}",
+                        quote_string(source.value.as_str()),
+                        name,
+                        get_type_arguments_string(&import_type.type_arguments)
+                    ),
+                    _ => UNSUPPORTED_TYPE.to_string(),
+                },
+                _ => UNSUPPORTED_TYPE.to_string(),
+            }
+        }
+        _ => UNSUPPORTED_TYPE.to_string(),
+    }
+}
+
+/// Returns the rightmost name of a type name, such as `User` in
+/// `import('./models').User`
+fn get_type_name_string<'a>(type_name: &TSTypeName<'a>) -> Option<&'a str> {
+    match type_name {
+        TSTypeName::IdentifierReference(id) => Some(id.name.as_str()),
+        TSTypeName::QualifiedName(qualified) => Some(qualified.right.name.as_str()),
+        TSTypeName::ThisExpression(_) => None,
+    }
+}
+
+/// Renders a qualified name in full, such as `Config.defaults` in `typeof Config.defaults`
+fn get_qualified_name_string(qualified: &TSQualifiedName) -> String {
+    let left = match &qualified.left {
+        TSTypeName::IdentifierReference(id) => id.name.as_str().to_string(),
+        TSTypeName::QualifiedName(left) => get_qualified_name_string(left),
+        TSTypeName::ThisExpression(_) => "this".to_string(),
+    };
+    format!("{}.{}", left, qualified.right.name.as_str())
+}
+
+/// Parses the module and the type name of an import type rendered as
+/// `import('./models').Api.Order`, starting at the opening parenthesis.
+/// Returns them with the position after the type name
+fn parse_import_type(chars: &[char], open: usize) -> Option<(String, String, usize)> {
+    let quote = *chars.get(open + 1)?;
+    if quote != '\'' {
+        return None;
+    }
+
+    let mut source = String::new();
+    let mut i = open + 2;
+    loop {
+        match *chars.get(i)? {
+            '\\' => {
+                source.push(*chars.get(i + 1)?);
+                i += 2;
+            }
+            c if c == quote => break,
+            c => {
+                source.push(c);
+                i += 1;
+            }
+        }
+    }
+    if chars.get(i + 1) != Some(&')') || chars.get(i + 2) != Some(&'.') {
+        return None;
+    }
+
+    let start = i + 3;
+    let mut end = start;
+    while end < chars.len()
+        && (chars[end].is_alphanumeric() || matches!(chars[end], '_' | '$' | '.'))
+    {
+        end += 1;
+    }
+    let name: String = chars[start..end].iter().collect();
+    if name.is_empty() {
+        return None;
+    }
+    Some((source, name, end))
+}
+
+/// Collects the modules referenced by import types such as `import('./models').User`
+#[derive(Default)]
+struct ImportTypeCollector {
+    sources: Vec<String>,
+}
+
+impl<'a> Visit<'a> for ImportTypeCollector {
+    fn visit_ts_import_type(&mut self, it: &TSImportType<'a>) {
+        if let TSType::TSLiteralType(literal) = &it.argument {
+            if let TSLiteral::StringLiteral(source) = &literal.literal {
+                self.sources.push(source.value.as_str().to_string());
+            }
+        }
+        walk::walk_ts_import_type(self, it);
+    }
+}
+
+/// Renders an object literal type such as `{ street: string; zip?: string }`.
+/// Object types with only call signatures such as `{ (): void }` are functions
+fn get_type_literal_string(members: &[TSSignature]) -> String {
+    let properties = collect_properties(members);
+    if properties.is_empty() && is_callable(members) {
+        return "Function".to_string();
+    }
+    get_properties_string(&properties)
+}
+
+fn get_properties_string(properties: &[PropertyInfo]) -> String {
+    let properties: Vec<String> = properties
+        .iter()
+        .map(|prop| {
+            // Keys that are not identifiers are quoted, as in `{ 'content-type': string }`
+            let name = if is_identifier(&prop.name) || prop.name.starts_with("[key: ") {
+                prop.name.clone()
+            } else {
+                quote_string(&prop.name)
+            };
+            format!(
+                "{}{}: {}",
+                name,
+                if prop.optional { "?" } else { "" },
+                prop.type_annotation
+            )
+        })
+        .collect();
+
+    if properties.is_empty() {
+        "{}".to_string()
+    } else {
+        format!("{{ {} }}", properties.join("; "))
+    }
+}
+
+/// How the type of a constant's initializer is inferred. Literals keep their
+/// literal types with `as const` and when they are the whole initializer of a
+/// `const`, otherwise they are widened to their primitive types
+#[derive(Clone, Copy, PartialEq)]
+enum ConstContext {
+    Declaration,
+    AsConst,
+    Widened,
+}
+
+/// Infers the type of a constant's initializer, such as `['admin', 'user']`
+/// for `['admin', 'user'] as const`
+fn get_expression_type_string(expr: &Expression, context: ConstContext) -> Option<String> {
+    let literal = |literal_type: String, widened_type: &str| {
+        if context == ConstContext::Widened {
+            widened_type.to_string()
+        } else {
+            literal_type
+        }
+    };
+    let nested_context = if context == ConstContext::AsConst {
+        ConstContext::AsConst
+    } else {
+        ConstContext::Widened
+    };
+
+    match expr {
+        Expression::TSAsExpression(as_expr) => match &as_expr.type_annotation {
+            TSType::TSTypeReference(type_ref) if matches!(&type_ref.type_name, TSTypeName::IdentifierReference(id) if id.name == "const") => {
+                get_expression_type_string(&as_expr.expression, ConstContext::AsConst)
+            }
+            type_annotation => Some(get_type_string(type_annotation)),
+        },
+        Expression::TSSatisfiesExpression(satisfies) => {
+            get_expression_type_string(&satisfies.expression, context)
+        }
+        Expression::ParenthesizedExpression(paren) => {
+            get_expression_type_string(&paren.expression, context)
+        }
+        Expression::StringLiteral(s) => Some(literal(quote_string(s.value.as_str()), "string")),
+        Expression::TemplateLiteral(t) if t.expressions.is_empty() => {
+            let text: String = t
+                .quasis
+                .iter()
+                .filter_map(|quasi| quasi.value.cooked.as_ref())
+                .map(|cooked| cooked.as_str())
+                .collect();
+            Some(literal(quote_string(&text), "string"))
+        }
+        Expression::NumericLiteral(n) => Some(literal(format_number(n.value), "number")),
+        Expression::UnaryExpression(unary) if unary.operator == UnaryOperator::UnaryNegation => {
+            match &unary.argument {
+                Expression::NumericLiteral(n) => Some(literal(format_number(-n.value), "number")),
+                _ => None,
+            }
+        }
+        Expression::BooleanLiteral(b) => Some(literal(b.value.to_string(), "boolean")),
+        Expression::NullLiteral(_) => Some("null".to_string()),
+        Expression::Identifier(id) if id.name == "undefined" => Some("undefined".to_string()),
+        Expression::Identifier(id) => Some(format!("typeof {}", id.name.as_str())),
+        Expression::ArrayExpression(array) => {
+            let elements: Option<Vec<String>> = array
+                .elements
+                .iter()
+                .map(|element| get_expression_type_string(element.as_expression()?, nested_context))
+                .collect();
+            let elements = elements?;
+
+            if context == ConstContext::AsConst {
+                return Some(format!("[{}]", elements.join(", ")));
+            }
+
+            let mut element_types: Vec<String> = Vec::new();
+            for element in elements {
+                if !element_types.contains(&element) {
+                    element_types.push(element);
+                }
+            }
+            match element_types.as_slice() {
+                [] => Some("any[]".to_string()),
+                [element_type] if !element_type.contains(' ') => {
+                    Some(format!("{}[]", element_type))
+                }
+                _ => Some(format!("({})[]", element_types.join(" | "))),
+            }
+        }
+        Expression::ObjectExpression(object) => {
+            let mut properties = Vec::new();
+            for property in &object.properties {
+                let ObjectPropertyKind::ObjectProperty(property) = property else {
+                    return None;
+                };
+                let name = match &property.key {
+                    PropertyKey::StaticIdentifier(id) => id.name.as_str().to_string(),
+                    PropertyKey::StringLiteral(s) => s.value.as_str().to_string(),
+                    PropertyKey::NumericLiteral(n) => format_number(n.value),
+                    _ => return None,
+                };
+                properties.push(PropertyInfo {
+                    name,
+                    type_annotation: get_expression_type_string(&property.value, nested_context)?,
+                    optional: false,
+                });
+            }
+            Some(get_properties_string(&properties))
+        }
+        _ => None,
+    }
+}
+
+/// Evaluates an enum member initializer following TypeScript's rules for
+/// constant enum expressions, returning `None` for computed members
+fn evaluate_enum_expression<'a>(
+    expr: &Expression,
+    enum_name: &str,
+    members: &[EnumMember],
+    enums: &impl Fn(&str) -> Option<&'a EnumInfo>,
+) -> Option<EnumValue> {
+    let evaluate = |expr| evaluate_enum_expression(expr, enum_name, members, enums);
+    let member_value = |target_enum: &str, target_member: &str| {
+        let members = if target_enum == enum_name {
+            members
+        } else {
+            &enums(target_enum)?.members
+        };
+        members
+            .iter()
+            .find(|member| member.name == target_member)
+            .map(|member| member.value.clone())
+            .filter(|value| !matches!(value, EnumValue::Computed))
+    };
+
+    match expr {
+        Expression::NumericLiteral(n) => Some(EnumValue::Number(n.value)),
+        Expression::StringLiteral(s) => Some(EnumValue::String(s.value.as_str().to_string())),
+        Expression::TemplateLiteral(template) => {
+            let mut text = String::new();
+            for (i, quasi) in template.quasis.iter().enumerate() {
+                text.push_str(quasi.value.cooked.as_ref()?.as_str());
+                if let Some(expression) = template.expressions.get(i) {
+                    text.push_str(&evaluate(expression)?.to_js_string());
+                }
+            }
+            Some(EnumValue::String(text))
+        }
+        Expression::ParenthesizedExpression(paren) => evaluate(&paren.expression),
+        Expression::UnaryExpression(unary) => {
+            let EnumValue::Number(n) = evaluate(&unary.argument)? else {
+                return None;
+            };
+            match unary.operator {
+                UnaryOperator::UnaryPlus => Some(EnumValue::Number(n)),
+                UnaryOperator::UnaryNegation => Some(EnumValue::Number(-n)),
+                UnaryOperator::BitwiseNot => Some(EnumValue::Number(!to_int32(n) as f64)),
+                _ => None,
+            }
+        }
+        Expression::BinaryExpression(binary) => {
+            let left = evaluate(&binary.left)?;
+            let right = evaluate(&binary.right)?;
+            match (binary.operator, left, right) {
+                (BinaryOperator::Addition, EnumValue::String(left), right) => {
+                    Some(EnumValue::String(left + &right.to_js_string()))
                 }
+                (BinaryOperator::Addition, left, EnumValue::String(right)) => {
+                    Some(EnumValue::String(left.to_js_string() + &right))
+                }
+                (operator, EnumValue::Number(left), EnumValue::Number(right)) => {
+                    evaluate_numeric_operator(operator, left, right).map(EnumValue::Number)
+                }
+                _ => None,
+            }
+        }
+        Expression::Identifier(id) => match id.name.as_str() {
+            "Infinity" => Some(EnumValue::Number(f64::INFINITY)),
+            "NaN" => Some(EnumValue::Number(f64::NAN)),
+            name => member_value(enum_name, name),
+        },
+        _ => {
+            let (target_enum, target_member) = get_enum_member_reference(expr)?;
+            member_value(&target_enum, &target_member)
+        }
+    }
+}
+
+/// Whether an import path names a package, such as `pkg` or `@scope/pkg/sub`,
+/// rather than a file
+fn is_package_specifier(import_path: &str) -> bool {
+    !import_path.starts_with('.') && !Path::new(import_path).is_absolute()
+}
+
+/// Returns the name of a type or namespace declaration
+fn get_declaration_name<'a>(declaration: &'a Declaration<'a>) -> Option<&'a Atom<'a>> {
+    match declaration {
+        Declaration::TSInterfaceDeclaration(interface) => Some(&interface.id.name),
+        Declaration::TSTypeAliasDeclaration(alias) => Some(&alias.id.name),
+        Declaration::TSEnumDeclaration(enum_decl) => Some(&enum_decl.id.name),
+        Declaration::ClassDeclaration(class) => class.id.as_ref().map(|id| &id.name),
+        Declaration::TSModuleDeclaration(namespace)
+            if namespace.kind != TSModuleDeclarationKind::Global =>
+        {
+            match &namespace.id {
+                TSModuleDeclarationName::Identifier(id) => Some(&id.name),
+                TSModuleDeclarationName::StringLiteral(_) => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Returns the dotted path of an expression such as `Api.Base`
+fn get_expression_path(expr: &Expression) -> Option<String> {
+    match expr {
+        Expression::Identifier(id) => Some(id.name.as_str().to_string()),
+        Expression::StaticMemberExpression(member) => Some(format!(
+            "{}.{}",
+            get_expression_path(&member.object)?,
+            member.property.name.as_str()
+        )),
+        _ => None,
+    }
+}
+
+/// Returns the enum and member named by `Enum.Member` or `Enum['Member']`
+fn get_enum_member_reference(expr: &Expression) -> Option<(String, String)> {
+    match expr {
+        Expression::StaticMemberExpression(member) => match &member.object {
+            Expression::Identifier(object) => Some((
+                object.name.as_str().to_string(),
+                member.property.name.as_str().to_string(),
+            )),
+            _ => None,
+        },
+        Expression::ComputedMemberExpression(member) => {
+            match (&member.object, &member.expression) {
+                (Expression::Identifier(object), Expression::StringLiteral(property)) => Some((
+                    object.name.as_str().to_string(),
+                    property.value.as_str().to_string(),
+                )),
+                _ => None,
+            }
+        }
+        Expression::ParenthesizedExpression(paren) => get_enum_member_reference(&paren.expression),
+        _ => None,
+    }
+}
+
+fn evaluate_numeric_operator(operator: BinaryOperator, left: f64, right: f64) -> Option<f64> {
+    let shift = |n: f64| to_uint32(n) & 0x1f;
+    let value = match operator {
+        BinaryOperator::Addition => left + right,
+        BinaryOperator::Subtraction => left - right,
+        BinaryOperator::Multiplication => left * right,
+        BinaryOperator::Division => left / right,
+        BinaryOperator::Remainder => left % right,
+        BinaryOperator::Exponential => left.powf(right),
+        BinaryOperator::ShiftLeft => to_int32(left).wrapping_shl(shift(right)) as f64,
+        BinaryOperator::ShiftRight => to_int32(left).wrapping_shr(shift(right)) as f64,
+        BinaryOperator::ShiftRightZeroFill => to_uint32(left).wrapping_shr(shift(right)) as f64,
+        BinaryOperator::BitwiseAnd => (to_int32(left) & to_int32(right)) as f64,
+        BinaryOperator::BitwiseOR => (to_int32(left) | to_int32(right)) as f64,
+        BinaryOperator::BitwiseXOR => (to_int32(left) ^ to_int32(right)) as f64,
+        _ => return None,
+    };
+    Some(value)
+}
+
+/// JavaScript's ToUint32 conversion used by bitwise operators
+fn to_uint32(n: f64) -> u32 {
+    if n.is_finite() {
+        n.trunc().rem_euclid(4294967296.0) as u32
+    } else {
+        0
+    }
+}
+
+/// JavaScript's ToInt32 conversion used by bitwise operators
+fn to_int32(n: f64) -> i32 {
+    to_uint32(n) as i32
+}
+
+impl EnumValue {
+    /// The value as JavaScript would convert it to a string
+    fn to_js_string(&self) -> String {
+        match self {
+            EnumValue::String(s) => s.clone(),
+            EnumValue::Number(n) => format_number(*n),
+            EnumValue::Computed => String::new(),
+        }
+    }
+}
+
+/// Formats a number as JavaScript would, such as `Infinity` or `1.5`
+pub fn format_number(n: f64) -> String {
+    if n.is_nan() {
+        return "NaN".to_string();
+    } else if n.is_infinite() {
+        return if n > 0.0 { "Infinity" } else { "-Infinity" }.to_string();
+    } else if n == 0.0 {
+        return "0".to_string();
+    } else if n < 0.0 {
+        return format!("-{}", format_number(-n));
+    }
+
+    // Follows `Number.prototype.toString`, which switches to exponential
+    // notation for numbers from 1e21 and below 1e-6
+    let exponential = format!("{:e}", n);
+    let (mantissa, exponent) = exponential.split_once('e').unwrap_or((&exponential, "0"));
+    let digits = mantissa.replace('.', "");
+    let point = exponent.parse::<i32>().unwrap_or(0) + 1;
+    let length = digits.len() as i32;
+
+    if length <= point && point <= 21 {
+        format!("{}{}", digits, "0".repeat((point - length) as usize))
+    } else if 0 < point && point <= 21 {
+        format!(
+            "{}.{}",
+            &digits[..point as usize],
+            &digits[point as usize..]
+        )
+    } else if -6 < point && point <= 0 {
+        format!("0.{}{}", "0".repeat(-point as usize), digits)
+    } else {
+        let sign = if point > 0 { "+" } else { "-" };
+        let fraction = if length > 1 {
+            format!(".{}", &digits[1..])
+        } else {
+            String::new()
+        };
+        format!("{}{}e{}{}", &digits[..1], fraction, sign, (point - 1).abs())
+    }
+}
+
+/// The name of a type in the name of its validator, such as `ApiOrder` for
+/// `Api.Order` or `MailerOptions` for `mailer.Options`
+pub fn validator_type_name(type_name: &str) -> String {
+    type_name
+        .split('.')
+        .map(|segment| {
+            let mut chars = segment.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// Quotes a string for use in generated code, such as a property name in an
+/// `in` check
+pub fn quote_string(text: &str) -> String {
+    let escaped = text
+        .replace('\\', "\\\\")
+        .replace('\'', "\\'")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r");
+    format!("'{}'", escaped)
+}
+
+/// Reads back a string quoted by `quote_string`
+pub fn unquote_string(quoted: &str) -> Option<String> {
+    let inner = quoted.strip_prefix('\'')?.strip_suffix('\'')?;
+    let mut text = String::new();
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            text.push(c);
+            continue;
+        }
+        match chars.next()? {
+            'n' => text.push('\n'),
+            'r' => text.push('\r'),
+            escaped => text.push(escaped),
+        }
+    }
+    Some(text)
+}
+
+pub fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+}
+
+/// Renders a template literal type such as `` `user_${string}` ``, escaping
+/// characters in the text that would otherwise end the template or start an
+/// interpolated type
+fn get_template_literal_string(template: &TSTemplateLiteralType) -> String {
+    let mut result = String::from("`");
+
+    for (i, quasi) in template.quasis.iter().enumerate() {
+        let text = quasi.value.cooked.as_ref().unwrap_or(&quasi.value.raw);
+        for c in text.chars() {
+            if matches!(c, '\\' | '`' | '$') {
+                result.push('\\');
+            }
+            result.push(c);
+        }
+        if let Some(ts_type) = template.types.get(i) {
+            result.push_str(&format!("${{{}}}", get_type_string(ts_type)));
+        }
+    }
+
+    result.push('`');
+    result
+}
+
+/// Renders a tuple element with `?` for optional elements and `...` for rest
+/// elements, dropping the labels of named members
+fn get_tuple_element_string(element: &TSTupleElement) -> String {
+    match element {
+        TSTupleElement::TSOptionalType(optional) => {
+            format!(
+                "{}?",
+                get_parenthesized_type_string(&optional.type_annotation)
+            )
+        }
+        TSTupleElement::TSRestType(rest) => match &rest.type_annotation {
+            // Named rest elements such as `...rest: string[]`
+            TSType::TSNamedTupleMember(member) => {
+                format!("...{}", get_tuple_element_string(&member.element_type))
+            }
+            type_annotation => format!("...{}", get_parenthesized_type_string(type_annotation)),
+        },
+        TSTupleElement::TSNamedTupleMember(member) => {
+            let element_str = get_tuple_element_string(&member.element_type);
+            if member.optional {
+                format!("{}?", element_str)
             } else {
-                "unknown".to_string()
+                element_str
             }
         }
-        _ => "unknown".to_string(),
+        _ => element
+            .as_ts_type()
+            .map(get_parenthesized_type_string)
+            .unwrap_or_else(|| UNSUPPORTED_TYPE.to_string()),
+    }
+}
+
+fn get_parenthesized_type_string(ts_type: &TSType) -> String {
+    if is_compound_type(ts_type) {
+        format!("({})", get_type_string(ts_type))
+    } else {
+        get_type_string(ts_type)
+    }
+}
+
+/// Whether a type needs parentheses when used as an array element
+fn is_compound_type(ts_type: &TSType) -> bool {
+    match ts_type {
+        TSType::TSUnionType(_) | TSType::TSIntersectionType(_) => true,
+        // Type queries and `keyof` are parenthesized so they are not confused
+        // with an indexed access or array of their operand
+        TSType::TSTypeQuery(_) => true,
+        TSType::TSTypeOperatorType(operator) => operator.operator == TSTypeOperatorOperator::Keyof,
+        TSType::TSParenthesizedType(paren) => is_compound_type(&paren.type_annotation),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// A directory for the modules of a test, unique to the test and process
+    pub(crate) fn module_dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("bagsakan-{}-{}", name, std::process::id()))
+    }
+
+    /// Writes modules to a directory and parses them as source files, removing
+    /// the directory once they have been parsed
+    pub(crate) fn parse_modules(dir: &Path, modules: &[(&str, &str)]) -> TypeScriptParser {
+        for (file, source) in modules {
+            let path = dir.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, source).unwrap();
+        }
+
+        let mut parser =
+            TypeScriptParser::new(r"validate([A-Z][a-zA-Z]+)", false, Vec::new(), Vec::new());
+        for (file, _) in modules {
+            parser.mark_as_source_file(&dir.join(file));
+        }
+        for (file, _) in modules {
+            parser.parse_file(&dir.join(file)).unwrap();
+        }
+        fs::remove_dir_all(dir).unwrap();
+        parser
+    }
+
+    #[test]
+    fn keys_constants_by_module() {
+        let parser = parse_modules(
+            &module_dir("constants-by-module"),
+            &[
+                (
+                    "a.ts",
+                    "export const Roles = ['admin', 'user'] as const\n\
+                     export type Role = (typeof Roles)[number]\n",
+                ),
+                (
+                    "b.ts",
+                    "export const Roles = ['x', 'y'] as const\n\
+                     export type Role = (typeof Roles)[number]\n",
+                ),
+                (
+                    "c.ts",
+                    "import { Roles as Names } from './b'\n\
+                     export type Name = (typeof Names)[number]\n",
+                ),
+            ],
+        );
+
+        assert_eq!(
+            parser.constants["Roles"].type_annotation,
+            "['admin', 'user']"
+        );
+        assert_eq!(parser.constants["Roles2"].type_annotation, "['x', 'y']");
+        assert_eq!(
+            parser.type_aliases["Role"].type_annotation,
+            "(typeof Roles)[number]"
+        );
+        assert_eq!(
+            parser.type_aliases["Role2"].type_annotation,
+            "(typeof Roles2)[number]"
+        );
+        assert_eq!(
+            parser.type_aliases["Name"].type_annotation,
+            "(typeof Roles2)[number]"
+        );
+    }
+
+    #[test]
+    fn names_numbers_like_javascript_on_every_path() {
+        let parser = parse_modules(
+            &module_dir("number-names"),
+            &[(
+                "numbers.ts",
+                "export const Limits = { 1e21: 0.1, 5: -1e21 } as const\n\
+                 export type Huge = 1e21\n\
+                 export interface Keys { 1e21: string; 0.10: number }\n",
+            )],
+        );
+
+        assert_eq!(
+            parser.constants["Limits"].type_annotation,
+            "{ '1e+21': 0.1; '5': -1e+21 }"
+        );
+        assert_eq!(parser.type_aliases["Huge"].type_annotation, "1e+21");
+        let names: Vec<&str> = parser.interfaces["Keys"]
+            .properties
+            .iter()
+            .map(|prop| prop.name.as_str())
+            .collect();
+        assert_eq!(names, ["1e+21", "0.1"]);
+    }
+
+    #[test]
+    fn chooses_the_first_namespaced_type_sharing_a_validator_name() {
+        let parser = parse_modules(
+            &module_dir("namespaced-validator-names"),
+            &[(
+                "orders.ts",
+                "export namespace AB { export interface Order { id: string } }\n\
+                 export namespace A { export interface BOrder { id: number } }\n\
+                 validateABOrder(1)\n",
+            )],
+        );
+
+        assert_eq!(parser.validator_functions[0].interface_name, "A.BOrder");
+    }
+
+    #[test]
+    fn records_the_fields_and_parameter_properties_of_classes() {
+        let parser = parse_modules(
+            &module_dir("class-fields"),
+            &[(
+                "cart.ts",
+                "export class Cart {\n\
+                   items: string[] = []\n\
+                   private secret = ''\n\
+                   constructor(public readonly owner: string, note: string) {}\n\
+                   get size(): number { return this.items.length }\n\
+                   add(item: string): void { this.items.push(item) }\n\
+                 }\n",
+            )],
+        );
+
+        let names: Vec<&str> = parser.interfaces["Cart"]
+            .properties
+            .iter()
+            .map(|prop| prop.name.as_str())
+            .collect();
+        assert_eq!(names, ["items", "owner"]);
     }
 }
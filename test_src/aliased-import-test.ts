@@ -0,0 +1,20 @@
+// Test validators for types imported under other names
+import { Shopper as Buyer } from './shoppers'
+import type { Client, PostalAddress } from './shopper-exports'
+
+export interface DeliveryOrder {
+  buyer: Buyer
+  client?: Client
+  shipping: PostalAddress
+}
+
+import { validateBuyer, validateDeliveryOrder } from './validators'
+
+console.log('Buyer valid:', validateBuyer({ id: 'u1', tier: 'pro' }))
+console.log(
+  'Delivery order valid:',
+  validateDeliveryOrder({
+    buyer: { id: 'u1', tier: 'free' },
+    shipping: { street: 'Main St 1', postalCode: '1000' },
+  }),
+)
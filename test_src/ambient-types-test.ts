@@ -0,0 +1,20 @@
+// Test types from ambient module declarations and global declarations
+import type { TrackEvent } from 'analytics-sdk'
+
+export interface AnalyticsBatch {
+  events: TrackEvent[]
+  toggles: FeatureToggle[]
+  session: SessionInfo
+}
+
+import { validateAnalyticsBatch, validateTrackEvent } from './validators'
+
+console.log('Track event valid:', validateTrackEvent({ name: 'signup', properties: { plan: 'pro' } }))
+console.log(
+  'Analytics batch valid:',
+  validateAnalyticsBatch({
+    events: [],
+    toggles: [{ key: 'beta', enabled: true }],
+    session: { userId: 'u1', startedAt: 0 },
+  }),
+)
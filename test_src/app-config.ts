@@ -0,0 +1,12 @@
+// Default-exported types used by default-export-test.ts
+export default interface AppConfig {
+  name: string
+  port: number
+  logLevel: LogLevel
+}
+
+export enum LogLevel {
+  Debug = 'debug',
+  Info = 'info',
+  Error = 'error',
+}
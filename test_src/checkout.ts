@@ -0,0 +1,10 @@
+// Checkout types augmented by types/checkout.d.ts, which is loaded before the
+// source files
+export interface Cart {
+  items: string[]
+}
+
+import { validateCart } from './validators'
+
+console.assert(validateCart({ items: ['book'], coupon: 'SPRING' }), 'cart should be valid')
+console.assert(!validateCart({ items: ['book'], coupon: 5 }), 'cart coupon should be a string')
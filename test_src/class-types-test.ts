@@ -0,0 +1,59 @@
+// Test classes as property types and validator targets
+// `Money` is listed in `nominalClasses`, so it is checked with `instanceof`
+export class Money {
+  constructor(
+    public readonly amount: number,
+    public readonly currency: string,
+  ) {}
+
+  add(other: Money): Money {
+    return new Money(this.amount + other.amount, this.currency)
+  }
+}
+
+export class Entity {
+  id: string = ''
+  protected createdBy = 'system'
+}
+
+export class Vendor extends Entity {
+  name: string
+  website?: string
+  private apiKey = ''
+  #secret = 0
+  static registry: Vendor[] = []
+  active = true
+
+  constructor(name: string) {
+    super()
+    this.name = name
+  }
+
+  get displayName(): string {
+    return this.name.toUpperCase()
+  }
+
+  contact(message: string): void {
+    console.log(message, this.apiKey, this.#secret)
+  }
+}
+
+export interface LineItem {
+  vendor: Vendor
+  price: Money
+  discount?: Money
+}
+
+import { validateLineItem } from './validators'
+
+console.log(
+  'Line item valid:',
+  validateLineItem({ vendor: new Vendor('Acme'), price: new Money(10, 'EUR') }),
+)
+console.assert(
+  validateLineItem({
+    vendor: { id: 'v1', name: 'Acme', active: true },
+    price: new Money(10, 'EUR'),
+  }),
+  'vendor data without methods or getters should be valid',
+)
@@ -0,0 +1,43 @@
+// Test types derived from constants with typeof, keyof and indexed access
+export const Roles = ['admin', 'editor', 'viewer'] as const
+
+export const Permissions = {
+  Read: 'read',
+  Write: 'write',
+  Delete: 'delete',
+} as const
+
+export const Defaults = {
+  pageSize: 20,
+  locale: 'en',
+}
+
+export type Role = (typeof Roles)[number]
+
+export type Permission = (typeof Permissions)[keyof typeof Permissions]
+
+export type PermissionName = keyof typeof Permissions
+
+export interface Membership {
+  role: Role
+  permissions: Permission[]
+  grantedBy: PermissionName
+  primaryRole: (typeof Roles)[0]
+  settings: typeof Defaults
+  pageSize: (typeof Defaults)['pageSize']
+}
+
+import { validateMembership, validateRole } from './validators'
+
+console.log('Role valid:', validateRole('admin'))
+console.log(
+  'Membership valid:',
+  validateMembership({
+    role: 'editor',
+    permissions: ['read', 'write'],
+    grantedBy: 'Delete',
+    primaryRole: 'admin',
+    settings: { pageSize: 10, locale: 'fr' },
+    pageSize: 50,
+  }),
+)
@@ -0,0 +1,5 @@
+// Contacts of the CRM, which share their name with helpdesk.ts
+export interface Contact {
+  name: string
+  email: string
+}
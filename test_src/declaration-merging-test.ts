@@ -0,0 +1,38 @@
+// Test interfaces merged from multiple declarations
+import { Theme } from './theme'
+
+declare module './theme' {
+  interface Theme {
+    accent?: string
+    palette: Palette
+  }
+}
+
+// Declarations in the same file are merged
+export interface Palette {
+  light: string
+}
+
+export interface Palette {
+  dark: string
+}
+
+// An interface merged with a class adds to the class's members
+export interface Widget {
+  theme: Theme
+}
+
+export class Widget {
+  label = ''
+}
+
+import { validateTheme, validateWidget } from './validators'
+
+const theme = {
+  primary: '#000',
+  secondary: '#fff',
+  palette: { light: '#eee', dark: '#111' },
+}
+
+console.log('Theme valid:', validateTheme(theme))
+console.log('Widget valid:', validateWidget({ label: 'Save', theme }))
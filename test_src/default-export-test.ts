@@ -0,0 +1,22 @@
+// Test default-exported interfaces, enums and classes
+import type AppConfig from './app-config'
+import Verbosity from './verbosity'
+import Session from './user-session'
+
+export interface CliContext {
+  config: AppConfig
+  verbosity: Verbosity
+  session?: Session
+}
+
+import { validateAppConfig, validateCliContext, validateVerbosity } from './validators'
+
+console.log('App config valid:', validateAppConfig({ name: 'api', port: 8080, logLevel: 'info' }))
+console.log('Verbosity valid:', validateVerbosity(Verbosity.Verbose))
+console.log(
+  'CLI context valid:',
+  validateCliContext({
+    config: { name: 'api', port: 8080, logLevel: 'debug' },
+    verbosity: Verbosity.Quiet,
+  }),
+)
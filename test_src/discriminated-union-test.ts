@@ -0,0 +1,41 @@
+// Test discriminated unions, which are validated by switching on the shared tag
+export interface Circle {
+  kind: 'circle'
+  radius: number
+}
+
+export interface Square {
+  kind: 'square'
+  size: number
+}
+
+export interface Rectangle {
+  kind: 'rectangle' | 'box'
+  width: number
+  height: number
+}
+
+export type Shape = Circle | Square | Rectangle
+
+export type ApiEvent =
+  | { type: 'created'; id: string }
+  | { type: 'deleted'; id: string; soft?: boolean }
+  | { type: 1; code: number }
+
+export interface Canvas {
+  shapes: Shape[]
+  selected: Circle | Square | null
+  lastEvent?: ApiEvent
+}
+
+import { validateCanvas, validateShape } from './validators'
+
+console.log('Shape valid:', validateShape({ kind: 'circle', radius: 2 }))
+console.log(
+  'Canvas valid:',
+  validateCanvas({
+    shapes: [{ kind: 'box', width: 1, height: 2 }],
+    selected: null,
+    lastEvent: { type: 'deleted', id: 'abc' },
+  }),
+)
@@ -0,0 +1,66 @@
+// Test enum members initialized with constant expressions
+export enum Direction {
+  Down = -1,
+  None,
+  Up,
+}
+
+export enum FileAccess {
+  None = 0,
+  Read = 1 << 0,
+  Write = 1 << 1,
+  Execute = 1 << 2,
+  ReadWrite = Read | Write,
+  All = ReadWrite | FileAccess.Execute,
+}
+
+export enum Step {
+  First = 10,
+  Second = First + 10,
+  Third = Second * 2,
+  Half = 0.5,
+}
+
+export const enum Prefix {
+  Base = 'app',
+  User = Base + ':user',
+  Admin = `${Base}:admin`,
+}
+
+export declare enum Remote {
+  Primary = 'primary',
+  Fallback = Remote.Primary + '-fallback',
+}
+
+// Members can reference enums declared later in the file
+export enum Access {
+  Owner = Clearance.Owner,
+  Guest = Clearance['Guest'],
+}
+
+export enum Clearance {
+  Owner = 100,
+  Guest = ~0,
+}
+
+export interface Workspace {
+  direction: Direction
+  access: FileAccess
+  step: Step
+  prefix: Prefix
+  remote?: Remote
+  clearance: Access
+}
+
+import { validateWorkspace } from './validators'
+
+console.log(
+  'Workspace valid:',
+  validateWorkspace({
+    direction: Direction.Up,
+    access: FileAccess.ReadWrite,
+    step: Step.Half,
+    prefix: Prefix.Admin,
+    clearance: Access.Guest,
+  }),
+)
@@ -0,0 +1,62 @@
+// Test enum member types such as `EventKind.Click`
+export enum EventKind {
+  Click = 'click',
+  KeyPress = 'key_press',
+  Scroll = 'scroll',
+}
+
+export enum Severity {
+  Debug,
+  Info,
+  Warning,
+  Error,
+}
+
+export interface ClickEvent {
+  kind: EventKind.Click
+  x: number
+  y: number
+}
+
+export interface KeyPressEvent {
+  kind: EventKind.KeyPress
+  key: string
+}
+
+export interface ScrollEvent {
+  kind: EventKind.Scroll
+  offset: number
+}
+
+export type UiEvent = ClickEvent | KeyPressEvent | ScrollEvent
+
+export interface AlertLog {
+  severity: Severity.Warning | Severity.Error
+  events: UiEvent[]
+  lastKind?: EventKind.Click | EventKind.KeyPress
+}
+
+// A local type sharing its name with a member of an unknown namespace
+export interface Request {
+  url: string
+}
+
+export interface HandledEvent {
+  event: UiEvent
+  request: Express.Request
+}
+
+import { validateAlertLog, validateClickEvent, validateHandledEvent } from './validators'
+
+console.log('Click event valid:', validateClickEvent({ kind: EventKind.Click, x: 1, y: 2 }))
+console.log(
+  'Alert log valid:',
+  validateAlertLog({
+    severity: Severity.Error,
+    events: [{ kind: EventKind.Scroll, offset: 120 }],
+  }),
+)
+console.assert(
+  validateHandledEvent({ event: { kind: EventKind.Scroll, offset: 0 }, request: { method: 'GET' } }),
+  '`Express.Request` should not be validated as the local `Request`',
+)
@@ -0,0 +1,32 @@
+// Test interfaces that extend other interfaces
+import { User } from './models'
+
+export interface Timestamped {
+  createdAt: string
+  updatedAt?: string
+}
+
+export interface Admin extends User, Timestamped {
+  permissions: string[]
+  // Overrides the optional tags property inherited from User
+  tags: string[]
+}
+
+export interface SuperAdmin extends Admin {
+  canImpersonate: boolean
+}
+
+import { validateAdmin, validateSuperAdmin } from './validators'
+
+const admin = {
+  id: 1,
+  name: 'Admin',
+  email: 'admin@example.com',
+  isActive: true,
+  tags: ['staff'],
+  permissions: ['users:write'],
+  createdAt: '2024-01-01T00:00:00Z',
+}
+
+console.log('Admin valid:', validateAdmin(admin))
+console.log('Super admin valid:', validateSuperAdmin({ ...admin, canImpersonate: false }))
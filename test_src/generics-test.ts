@@ -0,0 +1,53 @@
+// Test generic interfaces instantiated with type arguments
+import { User } from './models'
+
+export interface Page<T> {
+  items: T[]
+  total: number
+  first?: T
+}
+
+export interface Envelope<T, M = string> {
+  data: T
+  meta: M
+}
+
+export type Nullable<T> = T | null
+
+export enum Visibility {
+  Public = 'public',
+  Private = 'private',
+}
+
+export const Sizes = ['small', 'large'] as const
+
+export interface UserDirectory {
+  users: Page<User>
+  tags: Page<string>
+  owner: Nullable<User>
+  response: Envelope<User | null>
+  visibility: Page<Visibility | null>
+  sizes: Page<(typeof Sizes)[number]>
+}
+
+export interface UserPage extends Page<User> {
+  cursor: string
+}
+
+import { validatePage, validateUserDirectory, validateUserPage, validateUser } from './validators'
+
+const page = { items: [], total: 0 }
+
+console.log('Page valid:', validatePage(page, validateUser))
+console.log('User page valid:', validateUserPage({ ...page, cursor: 'abc' }))
+console.log(
+  'User directory valid:',
+  validateUserDirectory({
+    users: page,
+    tags: page,
+    owner: null,
+    response: { data: null, meta: 'ok' },
+    visibility: { items: [Visibility.Public, null], total: 2 },
+    sizes: { items: ['small'], total: 1 },
+  }),
+)
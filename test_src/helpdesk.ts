@@ -0,0 +1,5 @@
+// Contacts of the helpdesk, which share their name with crm.ts
+export interface Contact {
+  agentId: number
+  queue: 'billing' | 'technical'
+}
@@ -0,0 +1,35 @@
+// Test intersection types of interfaces, object literals and branded primitives
+import { User } from './models'
+
+export interface BaseMeta {
+  createdAt: string
+  updatedBy?: string
+}
+
+export interface Audited {
+  updatedBy: string
+  revision: number
+}
+
+export type UserId = string & { readonly __brand: 'UserId' }
+
+export type AuditedUser = User & Audited & { tags?: string[] }
+
+export interface Document {
+  id: UserId
+  meta: BaseMeta & { extra: string }
+  history: (BaseMeta & Audited)[]
+  owner?: AuditedUser
+}
+
+import { validateAuditedUser, validateDocument } from './validators'
+
+console.log(
+  'Document valid:',
+  validateDocument({
+    id: 'user-1',
+    meta: { createdAt: '2024-01-01', extra: 'draft' },
+    history: [{ createdAt: '2024-01-01', updatedBy: 'admin', revision: 1 }],
+  }),
+)
+console.log('AuditedUser valid:', validateAuditedUser({ id: 1, updatedBy: 'admin', revision: 2 }))
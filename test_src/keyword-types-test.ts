@@ -0,0 +1,49 @@
+// Test every TypeScript keyword type, including unknown, never and {}
+export interface KeywordTypes {
+  big: bigint
+  sym: symbol
+  obj: object
+  anything: unknown
+  loose: any
+  maybe?: unknown
+  impossible?: never
+  nonNullish: {}
+  ids: bigint[]
+  nothing: void
+}
+
+// Types that cannot be checked are still required to be present, unlike unknown
+export interface UncheckedTypes {
+  offset: -1 | 0 | 1
+  flags: { [K in 'read' | 'write']: boolean }
+  payload: string extends 'a' ? number : boolean
+  history: (string extends 'a' ? number : boolean)[]
+}
+
+import { validateKeywordTypes, validateUncheckedTypes } from './validators'
+
+console.log(
+  'KeywordTypes valid:',
+  validateKeywordTypes({
+    big: 10n,
+    sym: Symbol('id'),
+    obj: {},
+    anything: undefined,
+    loose: null,
+    nonNullish: 0,
+    ids: [1n, 2n],
+    nothing: undefined,
+  }),
+)
+console.assert(
+  validateUncheckedTypes({ offset: -1, flags: {}, payload: true, history: [] }),
+  'unchecked types should be valid',
+)
+console.assert(
+  !validateUncheckedTypes({ offset: -1, flags: {}, history: [] }),
+  'missing properties of unchecked types should be invalid',
+)
+console.assert(
+  !validateUncheckedTypes({ offset: -2, flags: {}, payload: true, history: [] }),
+  'negative literal types should be checked',
+)
@@ -0,0 +1,56 @@
+// Test method, getter, setter and call signatures
+export interface Formatter {
+  (value: unknown): string
+  locale: string
+}
+
+export interface Middleware {
+  new (options: object): object
+}
+
+export interface Plugin {
+  name: string
+  setup(host: PluginHost): void
+  teardown?(): Promise<void>
+  resolve(id: string): string
+  resolve(id: string, importer: string): string
+  get version(): number
+  set enabled(value: boolean)
+  format: Formatter
+  onError: (error: Error) => void
+  middleware?: Middleware
+  host?: PluginHost
+}
+
+export interface PluginHost {
+  plugins: Plugin[]
+  log: { (message: string): void }
+}
+
+import { validatePlugin } from './validators'
+
+const format = Object.assign((value: unknown) => String(value), { locale: 'en' })
+
+console.log(
+  'Plugin valid:',
+  validatePlugin({
+    name: 'example',
+    setup: () => {},
+    resolve: (id: string) => id,
+    version: 1,
+    enabled: true,
+    format,
+    onError: () => {},
+  }),
+)
+console.assert(
+  validatePlugin({
+    name: 'example',
+    setup: () => {},
+    resolve: (id: string) => id,
+    version: 1,
+    format,
+    onError: () => {},
+  }),
+  'a setter without a getter should not be required',
+)
@@ -0,0 +1,21 @@
+// Test types with the same name declared in different modules
+import { Contact } from './crm'
+import * as helpdesk from './helpdesk'
+
+export interface SupportTicket {
+  requester: Contact
+  assignee: helpdesk.Contact
+  watchers: Array<Contact | helpdesk.Contact>
+}
+
+import { validateContact, validateSupportTicket } from './validators'
+
+console.log('Contact valid:', validateContact({ name: 'Ada', email: 'ada@example.com' }))
+console.log(
+  'Support ticket valid:',
+  validateSupportTicket({
+    requester: { name: 'Ada', email: 'ada@example.com' },
+    assignee: { agentId: 7, queue: 'billing' },
+    watchers: [],
+  }),
+)
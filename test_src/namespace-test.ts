@@ -0,0 +1,34 @@
+// Test types declared in namespaces and exported with `export =`
+import * as mailer from './typings/mailer'
+import type { Transport } from './typings/mailer'
+
+export namespace Outbox {
+  export enum Priority {
+    Low,
+    High,
+  }
+
+  export interface Message {
+    to: string[]
+    priority: Priority
+    transport: mailer.Options
+  }
+}
+
+export interface Delivery {
+  message: Outbox.Message
+  status?: Transport.Status
+  retryPriority: Outbox.Priority.High
+}
+
+import { validateDelivery, validateMailerOptions, validateTransport } from './validators'
+
+console.log('Options valid:', validateMailerOptions({ host: 'smtp.example.com', port: 587 }))
+console.log('Transport valid:', validateTransport({ status: { connected: true, queued: 0 } }))
+console.log(
+  'Delivery valid:',
+  validateDelivery({
+    message: { to: ['ada@example.com'], priority: 1, transport: { host: 'localhost', port: 25 } },
+    retryPriority: 1,
+  }),
+)
@@ -0,0 +1,31 @@
+// Test anonymous object literal types, including nested and optional members
+import { User } from './models'
+
+export interface Customer {
+  name: string
+  address: {
+    street: string
+    zip?: string
+    geo: { lat: number; lng: number }
+  }
+  preferences?: { newsletter: boolean; channels: ('email' | 'sms')[] }
+  contacts: { label: string; user: User }[]
+  extra: {}
+  signOff: { text: "it's been a pleasure" | 'regards' | `C:\\inbox` }
+}
+
+export type Coordinates = { lat: number; lng: number }
+
+import { validateCoordinates, validateCustomer } from './validators'
+
+console.log('Coordinates valid:', validateCoordinates({ lat: 1, lng: 2 }))
+console.log(
+  'Customer valid:',
+  validateCustomer({
+    name: 'Ada',
+    address: { street: 'Main St', geo: { lat: 1, lng: 2 } },
+    contacts: [{ label: 'self', user: { id: 1, name: 'Ada', email: 'ada@example.com' } }],
+    extra: {},
+    signOff: { text: "it's been a pleasure" },
+  }),
+)
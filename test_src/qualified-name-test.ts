@@ -0,0 +1,32 @@
+// Test qualified type names from namespace imports and import() type references
+import * as models from './models'
+import { StockItem } from './storefront'
+
+export interface Shipment {
+  order: models.Order
+  recipient: import('./models').User
+  items: Array<models.Product>
+}
+
+// `import('./warehouse').StockItem` differs from the imported `StockItem`
+export interface Restock {
+  listed: StockItem
+  stored: import('./warehouse').StockItem
+}
+
+export interface TrackedShipment extends models.Order {
+  carrier: string
+}
+
+import { validateRestock, validateShipment, validateTrackedShipment } from './validators'
+
+console.log('Shipment valid:', validateShipment({ order: {}, recipient: {}, items: [] }))
+console.log('TrackedShipment valid:', validateTrackedShipment({ carrier: 'post' }))
+console.assert(
+  validateRestock({ stored: { sku: 's-1', bin: 'A4' }, listed: { sku: 's-1', price: 10 } }),
+  'restock should be valid',
+)
+console.assert(
+  !validateRestock({ stored: { sku: 's-1', price: 10 }, listed: { sku: 's-1', price: 10 } }),
+  'restock storing a storefront stock item should be invalid',
+)
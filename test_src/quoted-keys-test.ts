@@ -0,0 +1,56 @@
+// Test properties with quoted, numeric and computed literal keys
+export interface RequestHeaders {
+  'content-type': string
+  'x-request-id'?: string
+  "user's-locale": 'en' | 'fr'
+  ['x-computed']: number
+  host: string
+}
+
+export interface ErrorBody {
+  message: string
+}
+
+export interface ErrorResponses {
+  404: ErrorBody
+  '500'?: ErrorBody
+  nested: { 'retry-after': number; "owner's": string; 'C:\\temp': boolean }
+  1e21: ErrorBody
+  0.0000001?: ErrorBody
+}
+
+import { validateErrorResponses, validateRequestHeaders } from './validators'
+
+console.log(
+  'RequestHeaders valid:',
+  validateRequestHeaders({
+    'content-type': 'application/json',
+    "user's-locale": 'en',
+    'x-computed': 1,
+    host: 'example.com',
+  }),
+)
+console.log(
+  'ErrorResponses valid:',
+  validateErrorResponses({
+    404: { message: 'Not found' },
+    nested: { 'retry-after': 30, "owner's": 'ops', 'C:\\temp': true },
+    '1e+21': { message: 'Huge' },
+  }),
+)
+console.assert(
+  !validateErrorResponses({
+    404: { message: 'Not found' },
+    nested: { 'retry-after': 30, "owner's": 7, 'C:\\temp': true },
+    '1e+21': { message: 'Huge' },
+  }),
+  'quoted keys of nested object types should be checked',
+)
+console.assert(
+  !validateErrorResponses({
+    404: { message: 'Not found' },
+    nested: { 'retry-after': 30, "owner's": 'ops', 'C:\\temp': true },
+    '1e-7': 'Tiny',
+  }),
+  'exponent keys should be checked',
+)
@@ -0,0 +1,45 @@
+// Test index signatures and Record types with string, numeric and literal keys
+import { User } from './models'
+
+export type Locale = 'en' | 'fr'
+
+export enum Channel {
+  Email = 'email',
+  Sms = 'sms',
+}
+
+export interface Translations {
+  [key: string]: string
+}
+
+export interface FeatureFlags {
+  defaultEnabled: boolean
+  [flag: string]: boolean
+}
+
+export interface LookupTables {
+  usersById: Record<string, User>
+  labels: Record<Locale, string>
+  limits: Record<'min' | 'max', number>
+  podium: Record<1 | 2 | 3, string>
+  quotas: Record<Channel, number>
+  ranks: { [rank: number]: string }
+  scores?: Record<string, number[]>
+  translations: Translations
+}
+
+import { validateFeatureFlags, validateLookupTables } from './validators'
+
+console.log('FeatureFlags valid:', validateFeatureFlags({ defaultEnabled: true, beta: false }))
+console.log(
+  'LookupTables valid:',
+  validateLookupTables({
+    usersById: { '1': { id: 1, name: 'Ada', email: 'ada@example.com' } },
+    labels: { en: 'Hello', fr: 'Bonjour' },
+    limits: { min: 0, max: 10 },
+    podium: { 1: 'gold', 2: 'silver', 3: 'bronze' },
+    quotas: { email: 100, sms: 10 },
+    ranks: { 1: 'gold', 2: 'silver' },
+    translations: { greeting: 'Hello' },
+  }),
+)
@@ -0,0 +1,21 @@
+// Test a requested validator sharing its name with a dependency declared in
+// another module
+import * as storefront from './storefront'
+import { StockItem } from './warehouse'
+
+export interface Listing {
+  item: storefront.StockItem
+}
+
+import { validateListing, validateStockItem } from './validators'
+
+const item: StockItem = { sku: 's-1', bin: 'A4' }
+
+// `validateStockItem` checks warehouse stock items, so listings must call the
+// validator generated for storefront stock items instead
+console.assert(validateStockItem(item), 'warehouse stock item should be valid')
+console.assert(
+  validateListing({ item: { sku: 's-1', price: 10 } }),
+  'listing of a storefront stock item should be valid',
+)
+console.assert(!validateListing({ item }), 'listing of a warehouse stock item should be invalid')
@@ -0,0 +1,2 @@
+// Renamed re-exports of shoppers.ts
+export { Shopper as Client, Address as PostalAddress } from './shoppers'
@@ -0,0 +1,12 @@
+// Shopper types exported under other names by shopper-exports.ts
+export interface Shopper {
+  id: string
+  tier: 'free' | 'pro'
+}
+
+interface MailingAddress {
+  street: string
+  postalCode: string
+}
+
+export { MailingAddress as Address }
@@ -0,0 +1,5 @@
+// Stock items of the storefront, which share their name with warehouse.ts
+export interface StockItem {
+  sku: string
+  price: number
+}
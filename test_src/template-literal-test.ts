@@ -0,0 +1,28 @@
+// Test template literal types, checked with prefix/suffix checks or regular expressions
+export type Locale = 'en' | 'fr'
+
+export type CssLength = `${number}px` | `${number}%`
+
+export interface Resource {
+  id: `user_${string}`
+  slug: `${string}.html`
+  version: `v${number}.${number}`
+  localizedPath: `/${Locale}/${string}`
+  width: CssLength
+  flag?: `feature-${boolean}`
+  exact: `fixed`
+}
+
+import { validateResource } from './validators'
+
+console.log(
+  'Resource valid:',
+  validateResource({
+    id: 'user_123',
+    slug: 'index.html',
+    version: 'v1.2',
+    localizedPath: '/en/about',
+    width: '100px',
+    exact: 'fixed',
+  }),
+)
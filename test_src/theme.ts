@@ -0,0 +1,5 @@
+// Theme declarations augmented by declaration-merging-test.ts
+export interface Theme {
+  primary: string
+  secondary: string
+}
@@ -0,0 +1,43 @@
+// Test validators requested directly for enums, unions and primitive aliases
+export enum Currency {
+  Euro = 'EUR',
+  Dollar = 'USD',
+  Yen = 'JPY',
+}
+
+export enum Rounding {
+  Down,
+  HalfUp,
+  HalfEven,
+  Up,
+}
+
+export interface CardPayment {
+  type: 'card'
+  last4: string
+}
+
+export interface BankTransfer {
+  type: 'bank'
+  iban: string
+}
+
+export type PaymentMethod = CardPayment | BankTransfer
+
+export type InvoiceId = string
+
+export type InvoiceState = 'draft' | 'sent' | 'paid'
+
+import {
+  validateCurrency,
+  validateInvoiceId,
+  validateInvoiceState,
+  validatePaymentMethod,
+  validateRounding,
+} from './validators'
+
+console.log('Currency valid:', validateCurrency('EUR'))
+console.log('Rounding valid:', validateRounding(Rounding.HalfEven))
+console.log('Payment method valid:', validatePaymentMethod({ type: 'card', last4: '4242' }))
+console.log('Invoice id valid:', validateInvoiceId('INV-001'))
+console.log('Invoice state valid:', validateInvoiceState('paid'))
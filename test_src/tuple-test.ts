@@ -0,0 +1,29 @@
+// Test tuple types with optional, named and rest elements
+import { User } from './models'
+
+export interface Geometry {
+  coords: [number, number]
+  range?: readonly [min: number, max: number]
+  label: [string, number?]
+  path: [string, ...number[]]
+  framed: [...string[], User]
+  tagged: [head: number, ...rest: string[]]
+  pairs: [string, 'on' | 'off'][]
+}
+
+export type Point = [x: number, y: number, z?: number]
+
+import { validateGeometry, validatePoint } from './validators'
+
+console.log('Point valid:', validatePoint([1, 2]))
+console.log(
+  'Geometry valid:',
+  validateGeometry({
+    coords: [1, 2],
+    label: ['origin'],
+    path: ['route', 1, 2, 3],
+    framed: [],
+    tagged: [1, 'a', 'b'],
+    pairs: [['light', 'on']],
+  }),
+)
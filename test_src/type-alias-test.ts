@@ -0,0 +1,28 @@
+// Test type alias declarations as validator targets
+export type Account = {
+  id: number
+  owner: string
+  nickname?: string
+}
+
+export type AccountStatus = 'open' | 'frozen' | 'closed'
+
+export type AccountId = number
+
+export interface AccountSummary {
+  id: AccountId
+  status: AccountStatus
+  previousStatus?: AccountStatus
+  account: Account
+}
+
+import { validateAccount, validateAccountStatus, validateAccountSummary } from './validators'
+
+const account = {
+  id: 1,
+  owner: 'Jane Doe',
+}
+
+console.log('Account valid:', validateAccount(account))
+console.log('Account status valid:', validateAccountStatus('frozen'))
+console.log('Account summary valid:', validateAccountSummary({ id: 1, status: 'open', account }))
@@ -0,0 +1,14 @@
+// Ambient declarations loaded through `typeFiles` in bagsakan.toml
+interface FeatureToggle {
+  key: string
+  enabled: boolean
+  rollout?: number
+}
+
+declare module 'analytics-sdk' {
+  export interface TrackEvent {
+    name: string
+    properties: Record<string, string | number>
+    toggle?: FeatureToggle
+  }
+}
@@ -0,0 +1,8 @@
+// Augments a source module from a file loaded through `typeFiles` in bagsakan.toml
+export {}
+
+declare module '../checkout' {
+  interface Cart {
+    coupon?: string
+  }
+}
@@ -0,0 +1,9 @@
+// Global declarations from a module loaded through `typeFiles` in bagsakan.toml
+export {}
+
+declare global {
+  interface SessionInfo {
+    userId: string
+    startedAt: number
+  }
+}
@@ -0,0 +1,29 @@
+// Typings in the style of a DefinitelyTyped package, used by namespace-test.ts
+declare function mailer(options: mailer.Options): mailer.Transport
+
+declare namespace mailer {
+  interface Options {
+    host: string
+    port: number
+    secure?: boolean
+    auth?: Auth
+  }
+
+  interface Auth {
+    user: string
+    pass: string
+  }
+
+  interface Transport {
+    status: Transport.Status
+  }
+
+  namespace Transport {
+    interface Status {
+      connected: boolean
+      queued: number
+    }
+  }
+}
+
+export = mailer
@@ -0,0 +1,5 @@
+// A default-exported class used by default-export-test.ts
+export default class UserSession {
+  token = ''
+  expiresAt = 0
+}
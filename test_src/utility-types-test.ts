@@ -0,0 +1,44 @@
+// Test the built-in utility types Partial, Required, Pick, Omit, Readonly and NonNullable
+import { User } from './models'
+
+export interface Settings {
+  theme: 'light' | 'dark'
+  fontSize?: number
+  notifications: boolean
+}
+
+export interface Account {
+  id: number
+  email: string
+  password: string
+  nickname?: string | null
+}
+
+export type Nickname = string | null | undefined
+
+export type SettingsPatch = Partial<Settings>
+
+export type PublicAccount = Omit<Account, 'password'>
+
+export interface AccountDto {
+  summary: Pick<User, 'id' | 'name'>
+  settings: Required<Settings>
+  frozen: Readonly<Settings>
+  tags: Readonly<string[]>
+  nickname: NonNullable<Nickname>
+}
+
+import { validateAccountDto, validatePublicAccount, validateSettingsPatch } from './validators'
+
+console.log('SettingsPatch valid:', validateSettingsPatch({ theme: 'dark' }))
+console.log('PublicAccount valid:', validatePublicAccount({ id: 1, email: 'ada@example.com' }))
+console.log(
+  'AccountDto valid:',
+  validateAccountDto({
+    summary: { id: 1, name: 'Ada' },
+    settings: { theme: 'light', fontSize: 12, notifications: true },
+    frozen: { theme: 'light', notifications: false },
+    tags: [],
+    nickname: 'ada',
+  }),
+)
@@ -0,0 +1,8 @@
+// A default-exported enum used by default-export-test.ts
+enum Verbosity {
+  Quiet,
+  Normal,
+  Verbose,
+}
+
+export default Verbosity
@@ -0,0 +1,5 @@
+// Stock items of the warehouse, which share their name with storefront.ts
+export interface StockItem {
+  sku: string
+  bin: string
+}